@@ -2,9 +2,15 @@
 //! Definitions of the rendering commands that will be used to draw the UI elements.
 //! Those should be used as a reference for implementing custom renderers.
 
-use alloc::{collections::VecDeque, string::String};
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    string::String,
+};
 
-use crate::{elements::flexbox::FlexBox, style::border};
+use crate::{
+    elements::{KaolinElement, flexbox::FlexBox},
+    style::border,
+};
 
 /// A single rendering command.
 #[derive(Debug, Clone)]
@@ -14,6 +20,7 @@ where
 {
     /// Draws a rectangle on the screen.
     DrawRectangle {
+        /// The id of the element, empty if none was set.
         id: String,
         /// The absolute x position of the rectangle.
         x: f64,
@@ -35,6 +42,8 @@ where
     /// Multiple DrawText commands can be issued to draw a single text element
     /// on different lines.
     DrawText {
+        /// The id of the text element, empty if none was set.
+        id: String,
         /// The text to be drawn, in a single line. This is only converted to a
         /// String when generating the specific command to allow for safe deallocation
         /// of the input string within the [`KaolinScope::text`] call. Internally,
@@ -49,13 +58,51 @@ where
         font_id: u32,
         /// The font size of the text.
         font_size: f32,
+        /// Extra space between letters, in pixels, passed through from the style.
+        letter_spacing: f64,
         /// The color of the text.
         color: Color,
     },
 
+    /// Starts clipping all the following commands to the given area, until the
+    /// matching [`RenderCommand::ScissorEnd`].
+    ///
+    /// Scissors can be nested, in which case the areas should be intersected.
+    /// An axis that is not clipped spans from negative to positive infinity.
+    ScissorStart {
+        /// The id of the clipping element, empty if none was set.
+        id: String,
+        /// The absolute x position of the clipping area.
+        x: f64,
+        /// The absolute y position of the clipping area.
+        y: f64,
+        /// The width of the clipping area.
+        width: f64,
+        /// The height of the clipping area.
+        height: f64,
+    },
+    /// Stops the clipping started by the last [`RenderCommand::ScissorStart`].
+    ScissorEnd,
+    /// Draws an outline around the focused element, after everything else.
+    /// Only emitted when enabled with [`Kaolin::show_focus_outline`](crate::kaolin::Kaolin::show_focus_outline).
+    DrawFocusOutline {
+        /// The id of the focused element.
+        id: String,
+        /// The absolute x position of the focused element.
+        x: f64,
+        /// The absolute y position of the focused element.
+        y: f64,
+        /// The width of the focused element.
+        width: f64,
+        /// The height of the focused element.
+        height: f64,
+        /// The outline, drawn inside the element.
+        border: border::Border<Color>,
+    },
+
     /// A custom render command, which carries arbitrary data for the renderer to interpret.
     Custom {
-        /// not implemented yet
+        /// The id of the element, empty if none was set.
         id: String,
         /// The absolute x position of the custom element.
         x: f64,
@@ -70,6 +117,9 @@ where
     },
 }
 
+/// Commands are equal when they draw the same thing for the same element, so
+/// the id is compared for every command. The border of rectangles and the data
+/// of custom commands are left out, custom commands are never equal.
 impl<Color, CustomData> PartialEq for RenderCommand<Color, CustomData>
 where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
@@ -78,6 +128,7 @@ where
         match (self, other) {
             (
                 RenderCommand::DrawRectangle {
+                    id,
                     x,
                     y,
                     width,
@@ -87,6 +138,7 @@ where
                     ..
                 },
                 RenderCommand::DrawRectangle {
+                    id: other_id,
                     x: other_x,
                     y: other_y,
                     width: other_width,
@@ -96,7 +148,8 @@ where
                     ..
                 },
             ) => {
-                x == other_x
+                id == other_id
+                    && x == other_x
                     && y == other_y
                     && width == other_width
                     && height == other_height
@@ -105,43 +158,177 @@ where
             }
             (
                 RenderCommand::DrawText {
+                    id,
                     text,
                     x,
                     y,
                     font_id,
                     font_size,
+                    letter_spacing,
                     color,
+                    ..
                 },
                 RenderCommand::DrawText {
+                    id: other_id,
                     text: other_text,
                     x: other_x,
                     y: other_y,
                     font_id: other_font_id,
                     font_size: other_font_size,
+                    letter_spacing: other_letter_spacing,
                     color: other_color,
+                    ..
                 },
             ) => {
-                text == other_text
+                id == other_id
+                    && text == other_text
                     && x == other_x
                     && y == other_y
                     && font_id == other_font_id
                     && font_size == other_font_size
+                    && letter_spacing == other_letter_spacing
                     && color == other_color
             }
+            (
+                RenderCommand::ScissorStart {
+                    id,
+                    x,
+                    y,
+                    width,
+                    height,
+                    ..
+                },
+                RenderCommand::ScissorStart {
+                    id: other_id,
+                    x: other_x,
+                    y: other_y,
+                    width: other_width,
+                    height: other_height,
+                    ..
+                },
+            ) => {
+                id == other_id
+                    && x == other_x
+                    && y == other_y
+                    && width == other_width
+                    && height == other_height
+            }
+            (RenderCommand::ScissorEnd, RenderCommand::ScissorEnd) => true,
+            (
+                RenderCommand::DrawFocusOutline {
+                    id,
+                    x,
+                    y,
+                    width,
+                    height,
+                    border,
+                },
+                RenderCommand::DrawFocusOutline {
+                    id: other_id,
+                    x: other_x,
+                    y: other_y,
+                    width: other_width,
+                    height: other_height,
+                    border: other_border,
+                },
+            ) => {
+                id == other_id
+                    && x == other_x
+                    && y == other_y
+                    && width == other_width
+                    && height == other_height
+                    && border == other_border
+            }
             _ => false,
         }
     }
 }
 
+impl<Color, CustomData> RenderCommand<Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    /// Sets the id of the element the command belongs to, if the command has one.
+    pub(crate) fn set_id(&mut self, element_id: &str) {
+        match self {
+            RenderCommand::DrawRectangle { id, .. }
+            | RenderCommand::DrawText { id, .. }
+            | RenderCommand::ScissorStart { id, .. }
+            | RenderCommand::DrawFocusOutline { id, .. }
+            | RenderCommand::Custom { id, .. } => *id = String::from(element_id),
+            RenderCommand::ScissorEnd => {}
+        }
+    }
+}
+
+/// The final position and size of an element, after layout.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    /// The absolute x position of the element.
+    pub x: f64,
+    /// The absolute y position of the element.
+    pub y: f64,
+    /// The width of the element.
+    pub width: f64,
+    /// The height of the element.
+    pub height: f64,
+}
+
+impl BoundingBox {
+    /// True if the given point is within the bounding box.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+
+    /// ### Returns the part of the bounding box within the given area
+    /// Either of them may span infinitely on an axis, from negative infinity with
+    /// an infinite size. When the two don't overlap, the result is empty, at the
+    /// edge of the area.
+    pub fn intersection(&self, area: &BoundingBox) -> BoundingBox {
+        let (x, width) = intersect_span((self.x, self.width), (area.x, area.width));
+        let (y, height) = intersect_span((self.y, self.height), (area.y, area.height));
+        BoundingBox {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// Intersects a (start, size) span with an area span, either of which may be infinite.
+fn intersect_span((start, size): (f64, f64), (min, area_size): (f64, f64)) -> (f64, f64) {
+    let clipped_start = start.clamp(min, span_end(min, area_size));
+    let end = span_end(start, size).clamp(clipped_start, span_end(min, area_size));
+    (clipped_start, end - clipped_start)
+}
+
+/// The end of a (start, size) span, infinite if the size is.
+fn span_end(start: f64, size: f64) -> f64 {
+    if size.is_infinite() {
+        f64::INFINITY // avoids -inf + inf
+    } else {
+        start + size
+    }
+}
+
+/// The bounding boxes of the elements that were given an id, by id.
+/// Children of clipping containers only keep their visible part, so that the
+/// pointer can't hit what is hidden.
+pub type BoundingBoxes = BTreeMap<String, BoundingBox>;
+
 /// Represents a series of rendering commands.
 ///
 /// This struct implements an iterator of the render commands, which should be processed in order.
+/// The bounding boxes of the elements with an id can be queried at any time,
+/// see [`RenderCommands::bounding_box`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct RenderCommands<Color, CustomData>
 where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
 {
     commands: VecDeque<RenderCommand<Color, CustomData>>,
+    bounding_boxes: BoundingBoxes,
 }
 
 impl<Color, CustomData> RenderCommands<Color, CustomData>
@@ -149,13 +336,36 @@ where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
 {
     /// Creates a new set of render commands from a root layout.
+    ///
+    /// Floating elements are drawn after the normal flow, in ascending z-index order.
     pub(crate) fn new(root: FlexBox<Color, CustomData>) -> Self {
-        let children = root.children;
+        let mut commands = root.children.render_nodes().collect::<VecDeque<_>>();
+        let mut floating_layers = root.render_floating();
+        floating_layers.sort_by_key(|(z_index, _)| *z_index); // stable, keeps declaration order
+        commands.extend(floating_layers.into_iter().flat_map(|(_, layer)| layer));
+        let mut bounding_boxes = BoundingBoxes::new();
+        root.collect_bounding_boxes(&mut bounding_boxes);
         RenderCommands {
-            commands: children.render_nodes().collect::<VecDeque<_>>(),
+            commands,
+            bounding_boxes,
         }
     }
 
+    /// Appends a command, drawn after all the others.
+    pub(crate) fn push(&mut self, command: RenderCommand<Color, CustomData>) {
+        self.commands.push_back(command);
+    }
+
+    /// Returns the final bounding box of the element with the given id, if any.
+    pub fn bounding_box(&self, id: &str) -> Option<BoundingBox> {
+        self.bounding_boxes.get(id).copied()
+    }
+
+    /// Returns the final bounding boxes of all the elements with an id.
+    pub fn bounding_boxes(&self) -> &BoundingBoxes {
+        &self.bounding_boxes
+    }
+
     pub fn is_empty(&self) -> bool {
         self.commands.is_empty()
     }
@@ -0,0 +1,77 @@
+//! A custom element built from a closure, see [`KaolinScope::custom`](crate::kaolin::scope::KaolinScope::custom).
+
+use alloc::{boxed::Box, string::String};
+use core::{iter, marker::PhantomData};
+
+use crate::{
+    commands::RenderCommand,
+    elements::traits::{KaolinContainerElement, KaolinElement},
+    style::{
+        KaolinColor,
+        sizing::{BoxSizing, SizingDimensions},
+    },
+};
+
+/// An element emitting a single [`RenderCommand::Custom`], with the data
+/// produced by a closure from the final size of the element.
+///
+/// Example:
+/// ```ignore
+/// let chart = CustomElement::new(sizing!(grow!(), fixed!(40.0)), move |(width, height)| {
+///     Sparkline::new(&samples, width, height)
+/// });
+/// k.add_element(chart)
+/// ```
+pub struct CustomElement<Color, CustomData, F> {
+    sizing: BoxSizing,
+    draw: F,
+    /// Ties the element to the types of its render commands.
+    commands: PhantomData<(Color, CustomData)>,
+}
+
+impl<Color, CustomData, F> CustomElement<Color, CustomData, F>
+where
+    F: Fn((f64, f64)) -> CustomData,
+{
+    /// Creates a custom element with the given sizing, and a closure producing
+    /// its render data from its final (width, height).
+    pub fn new(sizing: BoxSizing, draw: F) -> Self {
+        CustomElement {
+            sizing,
+            draw,
+            commands: PhantomData,
+        }
+    }
+}
+
+impl<'frame, Color, CustomData, F> KaolinElement<'frame, Color, CustomData>
+    for CustomElement<Color, CustomData, F>
+where
+    Color: Default + Copy + PartialEq + KaolinColor,
+    F: Fn((f64, f64)) -> CustomData,
+{
+    fn get_sizing_dimensions(&self) -> (SizingDimensions, SizingDimensions) {
+        (self.sizing.width.into(), self.sizing.height.into())
+    }
+
+    fn render(
+        &self,
+        offsets: (f64, f64),
+        size: (f64, f64),
+    ) -> Box<dyn Iterator<Item = RenderCommand<Color, CustomData>> + '_> {
+        Box::new(iter::once(RenderCommand::Custom {
+            id: String::new(),
+            x: offsets.0,
+            y: offsets.1,
+            width: size.0,
+            height: size.1,
+            data: (self.draw)(size),
+        }))
+    }
+
+    fn as_container(
+        &mut self,
+    ) -> Option<&mut dyn KaolinContainerElement<'frame, Color, CustomData>> {
+        None
+    }
+}
@@ -1,37 +1,69 @@
-use alloc::{boxed::Box, string::ToString, vec::Vec};
-use core::{cmp::min_by, ops::Add};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cmp::min_by,
+    ops::{Add, Range},
+};
 
 use crate::{
-    commands::RenderCommand,
+    commands::{BoundingBox, BoundingBoxes, RenderCommand},
     elements::{
         KaolinNode, KaolinNodes,
-        traits::{KaolinContainerElement, KaolinElement},
+        floating::FloatingNodes,
+        traits::{FloatingLayer, KaolinContainerElement, KaolinElement, ScopeContainer},
     },
     style::{
         FlexStyle,
-        layout::{Alignment, Direction, Justification},
+        floating::Floating,
+        layout::{Alignment, Direction, Justification, LineAlignment, Wrap},
+        padding::Padding,
         sizing::SizingDimensions,
     },
 };
 
-pub(crate) struct FlexBox<'frame, Color, CustomData>
+/// A container laying out its children along an axis.
+///
+/// Opaque outside of the crate, it is only created by scopes and named by
+/// [`KaolinScope`](crate::kaolin::scope::KaolinScope).
+pub struct FlexBox<'frame, Color, CustomData>
 where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
 {
     style: FlexStyle<Color>,
+    /// The id of the container, set on its own render commands.
+    id: Option<String>,
     pub(crate) children: KaolinNodes<'frame, Color, CustomData>,
     pub(crate) inherited_color: Option<Color>,
+    /// Children taken out of the normal flow, see [`crate::style::floating::Floating`].
+    floating: FloatingNodes<'frame, Color, CustomData>,
+    /// Ranges of the children making up each line, only filled when wrapping.
+    lines: Vec<Range<usize>>,
+    /// The area the children are clipped to, set while positioning if the overflow clips.
+    clip: Option<BoundingBox>,
 }
 
 impl<'frame, Color, CustomData> FlexBox<'frame, Color, CustomData>
 where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
 {
-    pub fn new(style: FlexStyle<Color>) -> Self {
+    /// True if the children are laid out horizontally.
+    #[inline]
+    pub(crate) fn has_horizontal_layout(&self) -> bool {
+        self.style.has_horizontal_layout()
+    }
+
+    pub(crate) fn new(style: FlexStyle<Color>) -> Self {
         FlexBox {
             style,
+            id: None,
             children: KaolinNodes::new(),
             inherited_color: None,
+            floating: FloatingNodes::new(),
+            lines: Vec::new(),
+            clip: None,
         }
     }
 
@@ -39,6 +71,58 @@ where
         self.children.gaps() as f64 * self.style.layout.gap
     }
 
+    fn get_line_gaps(&self, line: &Range<usize>) -> f64 {
+        line.len().saturating_sub(1) as f64 * self.style.layout.gap
+    }
+
+    #[inline]
+    fn is_wrapping(&self) -> bool {
+        self.style.layout.wrap == Wrap::Wrap
+    }
+
+    /// Returns the lines the children are laid out on.
+    /// Without wrapping, all the children sit on a single line.
+    fn lines(&self) -> Vec<Range<usize>> {
+        if self.is_wrapping() && !self.lines.is_empty() {
+            self.lines.clone()
+        } else {
+            core::iter::once(0..self.children.nodes.len()).collect()
+        }
+    }
+
+    /// ### Returns the height the columns of a vertical wrapping container can take.
+    /// Columns are formed before the height is final, to fit and grow the width
+    /// to them, so they are broken at the height the container is bound to by its
+    /// sizing: its fixed or maximum height. Without one, children stay in a single column.
+    fn get_column_height(&self) -> f64 {
+        SizingDimensions::from(self.style.sizing.height).max() - self.style.padding.y()
+    }
+
+    /// Breaks the children into columns, see [`FlexBox::get_column_height`].
+    fn break_columns(&self) -> Vec<Range<usize>> {
+        self.children
+            .break_lines(self.get_column_height(), self.style.layout.gap, false)
+    }
+
+    /// Returns the size of a line in the cross axis.
+    ///
+    /// A single line takes all the usable cross dimension, like a container
+    /// that doesn't wrap, while multiple lines are as big as their biggest child.
+    fn get_line_cross_dimension(
+        &self,
+        line: &Range<usize>,
+        n_lines: usize,
+        usable_cross_dimension: f64,
+    ) -> f64 {
+        if n_lines <= 1 {
+            usable_cross_dimension
+        } else if self.style.has_horizontal_layout() {
+            self.children.get_line_max_height(line.clone())
+        } else {
+            self.children.get_line_max_width(line.clone())
+        }
+    }
+
     /// Fits the width of the flex container to its children, returning the new width.
     fn fit_width_to_children(&self) -> f64 {
         match self.style.layout.direction {
@@ -47,6 +131,17 @@ where
                     + self.style.padding.x()
                     + self.get_cumulative_gaps()
             }
+            Direction::TopToBottom | Direction::BottomToTop if self.is_wrapping() => {
+                // the columns sit side by side, as wide as their widest child
+                let columns = self.break_columns();
+                let n_gaps = columns.len().saturating_sub(1) as f64;
+                columns
+                    .into_iter()
+                    .map(|column| self.children.get_line_max_width(column))
+                    .sum::<f64>()
+                    + n_gaps * self.style.layout.cross_gap
+                    + self.style.padding.x()
+            }
             Direction::TopToBottom | Direction::BottomToTop => {
                 self.children.get_max_width() + self.style.padding.x()
             }
@@ -55,169 +150,271 @@ where
 
     /// Grows the width of all child elements to fit the container.
     pub(crate) fn grow_children_width(&mut self, current_width: f64) {
+        let usable_width = current_width - self.style.padding.x();
         if self.style.has_horizontal_layout() {
-            let cum_width = self.children.get_cumulative_width(); // lmao
-            let mut remaining =
-                current_width - self.style.padding.x() - cum_width - self.get_cumulative_gaps();
-
-            let shrinking = remaining < 0.0;
-
-            // create a list of children that will be subject to shrinking/growing
-            let mut modifiable_children = if shrinking {
-                self.children.get_shrinkable_children()
-            } else {
-                self.children.get_growable_children_w()
-            };
-
-            while remaining.abs() > 0.0 && !modifiable_children.is_empty() {
-                let mut total_change = 0.0;
-                // Get the current extreme widths
-                // the extreme is the starting point for the growth/shrinking, the second extreme is the stopping point for this iteration
-                let (extreme, second_extreme) = if shrinking {
-                    KaolinNodes::get_biggest_widths(&modifiable_children)
+            if self.is_wrapping() {
+                self.lines = self
+                    .children
+                    .break_lines(usable_width, self.style.layout.gap, true);
+            }
+            for line in self.lines() {
+                // percentages are of the usable width left by the gaps of their own line
+                self.children
+                    .resolve_percent_widths(line.clone(), usable_width - self.get_line_gaps(&line));
+                let cum_width = self.children.get_line_cumulative_width(line.clone()); // lmao
+                let mut remaining = usable_width - cum_width - self.get_line_gaps(&line);
+
+                let shrinking = remaining < 0.0;
+
+                // create a list of children that will be subject to shrinking/growing
+                let mut modifiable_children = if shrinking && self.style.overflow.x.clips() {
+                    Vec::new() // clipped content keeps its size
+                } else if shrinking {
+                    self.children.get_shrinkable_children_w(line)
                 } else {
-                    KaolinNodes::get_smallest_widths(&modifiable_children)
+                    self.children.get_growable_children_w(line)
                 };
 
-                // get the list of children that have the current extreme width
-                let currently_modifying = modifiable_children
-                    .iter_mut()
-                    .filter(|c| c.current_width == extreme)
-                    .collect::<Vec<_>>();
-
-                //  total factor for dividing the available space
-                let total_factor = if shrinking {
-                    currently_modifying.len() as f64
-                } else {
-                    currently_modifying
-                        .iter()
-                        .map(|c| c.get_grow_factor().0)
-                        .sum::<f64>()
-                };
+                while remaining.abs() > 0.0 && !modifiable_children.is_empty() {
+                    let mut total_change = 0.0;
+                    // Get the current extreme widths
+                    // the extreme is the starting point for the growth/shrinking, the second extreme is the stopping point for this iteration
+                    let (extreme, second_extreme) = if shrinking {
+                        KaolinNodes::get_biggest_widths(&modifiable_children)
+                    } else {
+                        KaolinNodes::get_smallest_widths(&modifiable_children)
+                    };
 
-                if total_factor <= 0.0 {
-                    break; // avoid infinite loops, means no progress can be made
-                }
+                    // get the list of children that have the current extreme width
+                    let currently_modifying = modifiable_children
+                        .iter_mut()
+                        .filter(|c| c.current_width == extreme)
+                        .collect::<Vec<_>>();
 
-                // calculate the base change amount for each child
-                let change_amount = min_by(remaining, second_extreme - extreme, |a, b| {
-                    a.abs()
-                        .partial_cmp(&b.abs())
-                        .unwrap_or_else(|| panic!("wtf"))
-                }) / total_factor;
-
-                for child in currently_modifying {
-                    // how much of the base change amount should this child get?
-                    let factor = if shrinking {
-                        1.0
+                    //  total factor for dividing the available space
+                    let total_factor = if shrinking {
+                        currently_modifying.len() as f64
                     } else {
-                        child.get_grow_factor().0
+                        currently_modifying
+                            .iter()
+                            .map(|c| c.get_grow_factor().0)
+                            .sum::<f64>()
                     };
-                    // grow the child to the new width
-                    let change = child.grow_width(change_amount * factor);
-                    total_change += change; // keep track of the total change
-                }
 
-                // remove the amount we changed from the remaining space
-                remaining -= total_change;
-                // retain only the children that can still be modified
-                modifiable_children.retain(|c| {
-                    if shrinking {
-                        c.shrinkable
-                    } else {
-                        c.growable_width
+                    if total_factor <= 0.0 {
+                        break; // avoid infinite loops, means no progress can be made
                     }
-                });
+
+                    // calculate the base change amount for each child
+                    let change_amount = min_by(remaining, second_extreme - extreme, |a, b| {
+                        a.abs()
+                            .partial_cmp(&b.abs())
+                            .unwrap_or_else(|| panic!("wtf"))
+                    }) / total_factor;
+
+                    for child in currently_modifying {
+                        // how much of the base change amount should this child get?
+                        let factor = if shrinking {
+                            1.0
+                        } else {
+                            child.get_grow_factor().0
+                        };
+                        // grow the child to the new width
+                        let change = child.grow_width(change_amount * factor);
+                        total_change += change; // keep track of the total change
+                    }
+
+                    // remove the amount we changed from the remaining space
+                    remaining -= total_change;
+                    // retain only the children that can still be modified
+                    modifiable_children.retain(|c| {
+                        if shrinking {
+                            c.shrinkable_width
+                        } else {
+                            c.growable_width
+                        }
+                    });
+                }
             }
         } else {
             // in cross axis, the growth is done individually for each child instead of sequentially
-            self.children.nodes().for_each(|child| {
-                // each has its own remaining space
-                let remaining = current_width - child.current_width - self.style.padding.x();
-                if (remaining > 0.0 && child.growable_width)
-                    || (remaining < 0.0 && child.shrinkable)
-                {
-                    child.grow_width(remaining);
-                }
-                child.growable_width = false; // Once grown, they can't grow anymore
-            });
+            let alignment = self.style.layout.alignment;
+            let shrink = !self.style.overflow.x.clips();
+            self.children
+                .resolve_percent_widths(0..self.children.nodes.len(), usable_width);
+            if self.is_wrapping() {
+                self.lines = self.break_columns();
+            }
+            let lines = self.lines();
+            let n_lines = lines.len();
+            for line in lines {
+                // with multiple columns, children grow up to the width of their own column
+                let line_width = self.get_line_cross_dimension(&line, n_lines, usable_width);
+                self.children.line_nodes(line).for_each(|child| {
+                    // each has its own remaining space
+                    let remaining = line_width - child.outer_width();
+                    if (remaining > 0.0 && child.growable_width)
+                        || (shrink && remaining < 0.0 && child.shrinkable_width)
+                    {
+                        child.grow_width(remaining);
+                    }
+                    if matches!(child.alignment(alignment), Alignment::Stretch) {
+                        child.stretch_width(line_width);
+                    }
+                    child.growable_width = false; // Once grown, they can't grow anymore
+                });
+            }
         }
         self.children.propagate_width_growth();
+        self.floating.grow_width(usable_width);
     }
 
     /// Grows the height of all child elements to fit the container.
     pub(crate) fn grow_children_height(&mut self, current_height: f64) {
+        let usable_height = current_height - self.style.padding.y();
         if self.style.has_horizontal_layout() {
-            self.children.nodes().for_each(|child| {
-                let remaining = current_height - child.current_height - self.style.padding.y();
-                if remaining > 0.0 && child.growable_height {
-                    child.grow_height(remaining);
-                }
-                child.growable_height = false; // Once grown, they can't grow anymore
-            });
-        } else {
-            let cum_height = self.children.get_cumulative_height(); // lmao
-            let mut remaining =
-                current_height - self.style.padding.y() - cum_height - self.get_cumulative_gaps();
-            let mut growable_children = self.children.get_growable_children_h();
-
-            while remaining > 0.0 && !growable_children.is_empty() {
-                let mut total_growth = 0.0;
-                let (smallest, second_smallest) =
-                    KaolinNodes::get_smallest_heights(&growable_children);
-
-                let growing = growable_children
-                    .iter_mut()
-                    .filter(|c| c.current_height == smallest)
-                    .collect::<Vec<_>>();
-
-                let total_factor = growing.iter().map(|c| c.get_grow_factor().1).sum::<f64>();
-                if total_factor > 0.0 {
-                    let grow_amount = remaining.min(second_smallest - smallest) / total_factor;
-                    for child in growing {
-                        let factor = child.get_grow_factor().1;
-                        let grow = child.grow_height(grow_amount * factor);
-                        total_growth += grow;
+            self.children
+                .resolve_percent_heights(0..self.children.nodes.len(), usable_height);
+            let alignment = self.style.layout.alignment;
+            let shrink = !self.style.overflow.y.clips();
+            let lines = self.lines();
+            let n_lines = lines.len();
+            for line in lines {
+                // with multiple lines, children grow up to the height of their own line
+                let line_height = self.get_line_cross_dimension(&line, n_lines, usable_height);
+                self.children.line_nodes(line).for_each(|child| {
+                    let remaining = line_height - child.outer_height();
+                    if (remaining > 0.0 && child.growable_height)
+                        || (shrink && remaining < 0.0 && child.shrinkable_height)
+                    {
+                        child.grow_height(remaining);
                     }
+                    if matches!(child.alignment(alignment), Alignment::Stretch) {
+                        child.stretch_height(line_height);
+                    }
+                    child.growable_height = false; // Once grown, they can't grow anymore
+                });
+            }
+        } else {
+            if self.is_wrapping() {
+                self.lines = self
+                    .children
+                    .break_lines(usable_height, self.style.layout.gap, false);
+            }
+            for line in self.lines() {
+                // percentages are of the usable height left by the gaps of their own line
+                self.children.resolve_percent_heights(
+                    line.clone(),
+                    usable_height - self.get_line_gaps(&line),
+                );
+                let cum_height = self.children.get_line_cumulative_height(line.clone()); // lmao
+                let mut remaining = usable_height - cum_height - self.get_line_gaps(&line);
+
+                let shrinking = remaining < 0.0;
+
+                // create a list of children that will be subject to shrinking/growing
+                let mut modifiable_children = if shrinking && self.style.overflow.y.clips() {
+                    Vec::new() // clipped content keeps its size
+                } else if shrinking {
+                    self.children.get_shrinkable_children_h(line)
                 } else {
-                    break; // avoid infinite loop
+                    self.children.get_growable_children_h(line)
+                };
+
+                while remaining.abs() > 0.0 && !modifiable_children.is_empty() {
+                    let mut total_change = 0.0;
+                    // same as the width: start from the extreme, stop at the second extreme
+                    let (extreme, second_extreme) = if shrinking {
+                        KaolinNodes::get_biggest_heights(&modifiable_children)
+                    } else {
+                        KaolinNodes::get_smallest_heights(&modifiable_children)
+                    };
+
+                    let currently_modifying = modifiable_children
+                        .iter_mut()
+                        .filter(|c| c.current_height == extreme)
+                        .collect::<Vec<_>>();
+
+                    let total_factor = if shrinking {
+                        currently_modifying.len() as f64
+                    } else {
+                        currently_modifying
+                            .iter()
+                            .map(|c| c.get_grow_factor().1)
+                            .sum::<f64>()
+                    };
+
+                    if total_factor <= 0.0 {
+                        break; // avoid infinite loop
+                    }
+
+                    let change_amount = min_by(remaining, second_extreme - extreme, |a, b| {
+                        a.abs()
+                            .partial_cmp(&b.abs())
+                            .unwrap_or_else(|| panic!("wtf"))
+                    }) / total_factor;
+
+                    for child in currently_modifying {
+                        let factor = if shrinking {
+                            1.0
+                        } else {
+                            child.get_grow_factor().1
+                        };
+                        let change = child.grow_height(change_amount * factor);
+                        total_change += change;
+                    }
+
+                    remaining -= total_change;
+                    modifiable_children.retain(|c| {
+                        if shrinking {
+                            c.shrinkable_height
+                        } else {
+                            c.growable_height
+                        }
+                    });
                 }
-                remaining -= total_growth;
-                growable_children.retain(|c| c.growable_height);
             }
         }
         self.children.do_grow_height();
+        self.floating.grow_height(usable_height);
     }
 
     /// Positions the child elements within the flex container.
     /// Called after all sizing calculations are complete.
     pub(crate) fn position_children(&mut self, offsets: (f64, f64, f64, f64)) {
         let (left, right, top, bottom) = offsets;
+        self.floating
+            .position((left, top, right - left, bottom - top));
+        self.clip = self.style.overflow.clips().then(|| {
+            let (x, y, width, height) =
+                self.scissor_area((left, top), (right - left, bottom - top));
+            BoundingBox {
+                x,
+                y,
+                width,
+                height,
+            }
+        });
+
+        // scrolled content is shifted back, floating children stay in place
+        let (scroll_x, scroll_y) = (
+            self.style.overflow.x.scroll_offset(),
+            self.style.overflow.y.scroll_offset(),
+        );
+        let (left, right, top, bottom) = (
+            left - scroll_x,
+            right - scroll_x,
+            top - scroll_y,
+            bottom - scroll_y,
+        );
 
         let (tot_main_dimension, tot_cross_dimension) =
             self.style.switch_axis((right - left, bottom - top));
 
-        // number of gaps between children
-        let n_gaps = self.children.gaps() as f64;
-        // cumulative space occupied by the children in the main axis
-        let cum_dimension = if self.style.has_horizontal_layout() {
-            self.children.get_cumulative_width()
-        } else {
-            self.children.get_cumulative_height()
-        };
-
-        // total usable empty space in the main axis
-        let empty_dimension = tot_main_dimension - cum_dimension;
-
-        // the actual distance the elements need to be apart in the main axis
-        let gap = match self.style.layout.justification {
-            Justification::SpaceBetween => empty_dimension / n_gaps,
-            Justification::SpaceAround => empty_dimension / (n_gaps + 2.0),
-            _ => 0.0,
-        }
-        .max(self.style.layout.gap); // never go below the set gap
-
-        let leftover_dimension = empty_dimension - gap * n_gaps; // unused space by the children in the main axis
+        // cumulative padding in both dimensions
+        let (main_pad, cross_pad) = self
+            .style
+            .switch_axis((self.style.padding.x(), self.style.padding.y()));
 
         // first drawable point for the children in both dimensions
         let (main_starting_offset, cross_starting_offset) = self
@@ -225,49 +422,120 @@ where
             .switch_axis((left + self.style.padding.left, top + self.style.padding.top));
 
         // last drawable point for the children in both dimensions
-        let (main_ending_offset, cross_ending_offset) = self.style.switch_axis((
+        let (main_ending_offset, _) = self.style.switch_axis((
             right - self.style.padding.right,
             bottom - self.style.padding.bottom,
         ));
 
-        // cumulative padding in both dimensions
-        let (main_pad, cross_pad) = self
-            .style
-            .switch_axis((self.style.padding.x(), self.style.padding.y()));
+        // empty space for all the lines in the cross dimension
+        let usable_cross_dimension = tot_cross_dimension - cross_pad;
 
-        // main axis position
-        let mut main_axis = match self.style.layout.justification {
-            Justification::Start | Justification::SpaceBetween => {
-                main_starting_offset // left offset and padding
-            }
-            Justification::SpaceAround => main_starting_offset + gap, // space around adds the gap to the outside
-            Justification::End => main_ending_offset - cum_dimension - gap * n_gaps, // whatever is missing from the total, including right padding
-            Justification::Center => main_starting_offset + (leftover_dimension - main_pad) / 2.0,
+        let lines = self.lines();
+        let n_lines = lines.len();
+        let line_cross_dimensions = lines
+            .iter()
+            .map(|line| self.get_line_cross_dimension(line, n_lines, usable_cross_dimension))
+            .collect::<Vec<_>>();
+
+        // the lines are distributed in the cross axis like children are in the main axis
+        let n_line_gaps = n_lines.saturating_sub(1) as f64;
+        let empty_cross_dimension =
+            usable_cross_dimension - line_cross_dimensions.iter().sum::<f64>();
+        let line_gap = match self.style.layout.line_alignment {
+            LineAlignment::SpaceBetween if n_lines > 1 => empty_cross_dimension / n_line_gaps,
+            _ => 0.0,
+        }
+        .max(self.style.layout.cross_gap); // never go below the set gap
+        let leftover_cross_dimension = empty_cross_dimension - line_gap * n_line_gaps;
+
+        let mut line_cross_offset = match self.style.layout.line_alignment {
+            LineAlignment::Start | LineAlignment::SpaceBetween => cross_starting_offset,
+            LineAlignment::Center => cross_starting_offset + leftover_cross_dimension / 2.0,
+            LineAlignment::End => cross_starting_offset + leftover_cross_dimension,
         };
 
-        // empty space for each child in the cross dimension
-        let usable_cross_dimension = tot_cross_dimension - cross_pad;
+        for (line, line_cross_dimension) in lines.into_iter().zip(line_cross_dimensions) {
+            // number of gaps between children
+            let n_gaps = line.len().saturating_sub(1) as f64;
+            // cumulative space occupied by the children in the main axis
+            let cum_dimension = if self.style.has_horizontal_layout() {
+                self.children.get_line_cumulative_width(line.clone())
+            } else {
+                self.children.get_line_cumulative_height(line.clone())
+            };
 
-        for child in self.children.nodes() {
-            let (main_child_dimension, cross_child_dimension) = self
-                .style
-                .switch_axis((child.current_width, child.current_height));
+            // total usable empty space in the main axis
+            let empty_dimension = tot_main_dimension - cum_dimension;
 
-            let cross_axis = match self.style.layout.alignment {
-                Alignment::Start | Alignment::Stretch => cross_starting_offset,
-                Alignment::Center => {
-                    cross_starting_offset + (usable_cross_dimension - cross_child_dimension) / 2.0
+            // the actual distance the elements need to be apart in the main axis
+            let gap = match self.style.layout.justification {
+                Justification::SpaceBetween => empty_dimension / n_gaps,
+                Justification::SpaceAround => empty_dimension / (n_gaps + 2.0),
+                _ => 0.0,
+            }
+            .max(self.style.layout.gap); // never go below the set gap
+
+            let leftover_dimension = empty_dimension - gap * n_gaps; // unused space by the children in the main axis
+
+            // main axis position
+            let mut main_axis = match self.style.layout.justification {
+                Justification::Start | Justification::SpaceBetween => {
+                    main_starting_offset // left offset and padding
+                }
+                Justification::SpaceAround => main_starting_offset + gap, // space around adds the gap to the outside
+                Justification::End => main_ending_offset - cum_dimension - gap * n_gaps, // whatever is missing from the total, including right padding
+                Justification::Center => {
+                    main_starting_offset + (leftover_dimension - main_pad) / 2.0
                 }
-                Alignment::End => cross_ending_offset - cross_child_dimension,
             };
-            if self.style.has_horizontal_layout() {
-                child.set_position(main_axis, cross_axis);
-            } else {
-                child.set_position(cross_axis, main_axis);
+
+            for child in self.children.line_nodes(line) {
+                let (main_child_dimension, cross_child_dimension) = self
+                    .style
+                    .switch_axis((child.outer_width(), child.outer_height()));
+
+                let cross_axis = match child.alignment(self.style.layout.alignment) {
+                    Alignment::Start | Alignment::Stretch => line_cross_offset,
+                    Alignment::Center => {
+                        line_cross_offset + (line_cross_dimension - cross_child_dimension) / 2.0
+                    }
+                    Alignment::End => {
+                        line_cross_offset + line_cross_dimension - cross_child_dimension
+                    }
+                };
+                // reversed directions mirror the main axis within the padded box,
+                // so the first child ends up at the end edge
+                let main_position = if self.style.layout.direction.is_reversed() {
+                    main_starting_offset + main_ending_offset - main_axis - main_child_dimension
+                } else {
+                    main_axis
+                };
+                if self.style.has_horizontal_layout() {
+                    child.set_position(main_position, cross_axis);
+                } else {
+                    child.set_position(cross_axis, main_position);
+                }
+                main_axis += main_child_dimension + gap;
             }
-            main_axis += main_child_dimension + gap;
+            line_cross_offset += line_cross_dimension + line_gap;
         }
     }
+
+    /// ### Returns the area the children are clipped to (x, y, width, height).
+    /// Axes that are not clipped span infinitely.
+    fn scissor_area(&self, offsets: (f64, f64), size: (f64, f64)) -> (f64, f64, f64, f64) {
+        let (x, width) = if self.style.overflow.x.clips() {
+            (offsets.0, size.0)
+        } else {
+            (f64::NEG_INFINITY, f64::INFINITY)
+        };
+        let (y, height) = if self.style.overflow.y.clips() {
+            (offsets.1, size.1)
+        } else {
+            (f64::NEG_INFINITY, f64::INFINITY)
+        };
+        (x, y, width, height)
+    }
 }
 
 impl<'frame, Color, CustomData> KaolinElement<'frame, Color, CustomData>
@@ -285,9 +553,42 @@ where
         (width, height)
     }
 
+    fn align_self(&self) -> Option<Alignment> {
+        self.style.align_self
+    }
+
+    fn aspect_ratio(&self) -> Option<f64> {
+        self.style.aspect_ratio
+    }
+
+    fn margin(&self) -> Padding {
+        self.style.margin
+    }
+
     fn fit_height_unbound(&mut self, _final_width: f64) -> f64 {
-        if self.style.has_horizontal_layout() {
+        if self.style.has_horizontal_layout() && self.is_wrapping() {
+            // the lines are already known, since the width is final
+            let lines = self.lines();
+            let n_line_gaps = lines.len().saturating_sub(1) as f64;
+            lines
+                .into_iter()
+                .map(|line| self.children.get_line_max_height(line))
+                .sum::<f64>()
+                + n_line_gaps * self.style.layout.cross_gap
+                + self.style.padding.y()
+        } else if self.style.has_horizontal_layout() {
             self.children.get_max_height().add(self.style.padding.y())
+        } else if self.is_wrapping() {
+            // the columns are formed again, as fitting the width may change the heights
+            self.lines = self.break_columns();
+            self.lines
+                .iter()
+                .map(|column| {
+                    self.children.get_line_cumulative_height(column.clone())
+                        + self.get_line_gaps(column)
+                })
+                .fold(0.0, f64::max)
+                + self.style.padding.y()
         } else {
             self.children.get_cumulative_height()
                 + self.get_cumulative_gaps()
@@ -308,8 +609,9 @@ where
         offsets: (f64, f64),
         size: (f64, f64),
     ) -> Box<dyn Iterator<Item = RenderCommand<Color, CustomData>> + '_> {
+        let id = self.id.as_deref().unwrap_or_default();
         let self_command = RenderCommand::DrawRectangle {
-            id: "".to_string(),
+            id: id.to_string(),
             x: offsets.0,
             y: offsets.1,
             width: size.0,
@@ -321,7 +623,45 @@ where
             corner_radius: self.style.corner_radius,
             border: self.style.border,
         };
-        Box::new(core::iter::once(self_command).chain(self.children.render_nodes()))
+        if !self.style.overflow.clips() {
+            return Box::new(core::iter::once(self_command).chain(self.children.render_nodes()));
+        }
+        let (x, y, width, height) = self.scissor_area(offsets, size);
+        let scissor_start = RenderCommand::ScissorStart {
+            id: id.to_string(),
+            x,
+            y,
+            width,
+            height,
+        };
+        Box::new(
+            [self_command, scissor_start]
+                .into_iter()
+                .chain(self.children.render_nodes())
+                .chain(core::iter::once(RenderCommand::ScissorEnd)),
+        )
+    }
+
+    fn render_floating(&self) -> Vec<FloatingLayer<'_, Color, CustomData>> {
+        let mut layers = self.children.render_floating();
+        layers.extend(self.floating.render());
+        layers
+    }
+
+    fn collect_bounding_boxes(&self, boxes: &mut BoundingBoxes) {
+        match &self.clip {
+            None => self.children.collect_bounding_boxes(boxes),
+            Some(clip) => {
+                let mut children = BoundingBoxes::new();
+                self.children.collect_bounding_boxes(&mut children);
+                boxes.extend(
+                    children
+                        .into_iter()
+                        .map(|(id, bounding_box)| (id, bounding_box.intersection(clip))),
+                );
+            }
+        }
+        self.floating.collect_bounding_boxes(boxes);
     }
 
     fn as_container(
@@ -336,6 +676,7 @@ impl<'frame, Color, CustomData> KaolinContainerElement<'frame, Color, CustomData
 where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
 {
+    #[allow(private_interfaces)]
     fn add_child(&mut self, child: KaolinNode<'frame, Color, CustomData>) {
         self.children.push(child);
     }
@@ -348,3 +689,25 @@ where
         self.grow_children_height(parent_height);
     }
 }
+
+impl<'frame, Color, CustomData> ScopeContainer<'frame, Color, CustomData>
+    for FlexBox<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    fn children_color(&self) -> Option<Color> {
+        self.inherited_color
+    }
+
+    fn set_id(&mut self, id: Option<String>) {
+        self.id = id;
+    }
+
+    fn add_floating_child(
+        &mut self,
+        child: KaolinNode<'frame, Color, CustomData>,
+        floating: Floating,
+    ) {
+        self.floating.push(child, floating);
+    }
+}
@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+
+use crate::{
+    commands::BoundingBoxes,
+    elements::{KaolinNode, KaolinNodes, traits::FloatingLayer},
+    style::floating::Floating,
+};
+
+/// The floating children of a container, kept out of the normal flow.
+pub(crate) struct FloatingNodes<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    nodes: KaolinNodes<'frame, Color, CustomData>,
+    configs: Vec<Floating>,
+}
+
+impl<'frame, Color, CustomData> FloatingNodes<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    pub fn new() -> Self {
+        FloatingNodes {
+            nodes: KaolinNodes::new(),
+            configs: Vec::new(),
+        }
+    }
+
+    /// Add a new floating child
+    pub fn push(&mut self, node: KaolinNode<'frame, Color, CustomData>, floating: Floating) {
+        self.nodes.push(node);
+        self.configs.push(floating);
+    }
+
+    /// Grows the floating children to the usable width of what they're attached to,
+    /// if they are growable, and propagates the growth to their children.
+    pub fn grow_width(&mut self, usable_width: f64) {
+        self.nodes.nodes().for_each(|node| {
+            let remaining = usable_width - node.outer_width();
+            if remaining > 0.0 && node.growable_width {
+                node.grow_width(remaining);
+            }
+            node.growable_width = false;
+        });
+        self.nodes.propagate_width_growth();
+    }
+
+    /// Grows the floating children to the usable height of what they're attached to,
+    /// if they are growable, and propagates the growth to their children.
+    pub fn grow_height(&mut self, usable_height: f64) {
+        self.nodes.nodes().for_each(|node| {
+            let remaining = usable_height - node.outer_height();
+            if remaining > 0.0 && node.growable_height {
+                node.grow_height(remaining);
+            }
+            node.growable_height = false;
+        });
+        self.nodes.do_grow_height();
+    }
+
+    /// Positions the floating children, anchored to the given bounding box (x, y, width, height).
+    pub fn position(&mut self, parent: (f64, f64, f64, f64)) {
+        for (node, floating) in self.nodes.nodes.iter_mut().zip(&self.configs) {
+            let (x, y) = floating.position(parent, (node.outer_width(), node.outer_height()));
+            node.set_position(x, y);
+        }
+    }
+
+    /// Collects the final bounding boxes of the floating children with an id, at any depth.
+    pub fn collect_bounding_boxes(&self, boxes: &mut BoundingBoxes) {
+        self.nodes.collect_bounding_boxes(boxes);
+    }
+
+    /// Renders the floating children into layers, including the floating
+    /// elements nested within them.
+    pub fn render(&self) -> Vec<FloatingLayer<'_, Color, CustomData>> {
+        let mut layers = Vec::new();
+        for (node, floating) in self.nodes.nodes.iter().zip(&self.configs) {
+            layers.push((floating.z_index, node.render()));
+            layers.extend(node.element.render_floating());
+        }
+        layers
+    }
+}
@@ -0,0 +1,479 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    commands::{BoundingBoxes, RenderCommand},
+    elements::{
+        KaolinNode, KaolinNodes,
+        floating::FloatingNodes,
+        traits::{FloatingLayer, KaolinContainerElement, KaolinElement, ScopeContainer},
+    },
+    style::{
+        floating::Floating,
+        grid::{GridCell, GridStyle},
+        layout::Alignment,
+        sizing::{Sizing, SizingDimensions},
+    },
+};
+
+/// A container placing its children in the cells of a grid of column and row tracks.
+///
+/// Opaque outside of the crate, it is only created by scopes and named by
+/// [`GridScope`](crate::kaolin::scope::GridScope).
+pub struct Grid<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    style: GridStyle<Color>,
+    /// The id of the container, set on its own render commands.
+    id: Option<String>,
+    pub(crate) children: KaolinNodes<'frame, Color, CustomData>,
+    pub(crate) inherited_color: Option<Color>,
+    /// Children taken out of the normal flow, see [`crate::style::floating::Floating`].
+    floating: FloatingNodes<'frame, Color, CustomData>,
+    /// The cell of each child, in the same order as the children.
+    cells: Vec<GridCell>,
+    /// Where the next automatically placed child will be looked for.
+    cursor: (usize, usize),
+    /// The cell of the next child, if placed explicitly, see [`Grid::place_next`].
+    next_cell: Option<GridCell>,
+    /// Final sizes of the column tracks, set while growing the width.
+    column_widths: Vec<f64>,
+    /// Final sizes of the row tracks, set while growing the height.
+    row_heights: Vec<f64>,
+}
+
+impl<'frame, Color, CustomData> Grid<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    pub(crate) fn new(style: GridStyle<Color>) -> Self {
+        Grid {
+            style,
+            id: None,
+            children: KaolinNodes::new(),
+            inherited_color: None,
+            floating: FloatingNodes::new(),
+            cells: Vec::new(),
+            cursor: (0, 0),
+            next_cell: None,
+            column_widths: Vec::new(),
+            row_heights: Vec::new(),
+        }
+    }
+
+    /// Places the next child added to the grid in the given cell, instead of
+    /// the next free one.
+    pub(crate) fn place_next(&mut self, cell: GridCell) {
+        self.next_cell = Some(cell);
+    }
+
+    /// Adds a child in the given cell.
+    fn add_child_at(&mut self, child: KaolinNode<'frame, Color, CustomData>, cell: GridCell) {
+        let cell = cell
+            .row_span(cell.row_span.max(1))
+            .column_span(cell.column_span.max(1));
+        self.children.push(child);
+        self.cells.push(cell);
+    }
+
+    /// Finds the next free cell in row-major order, starting from the cursor,
+    /// and moves the cursor right after it.
+    fn next_free_cell(&mut self) -> GridCell {
+        let n_columns = self.style.columns.len().max(1);
+        let (mut row, mut column) = self.cursor;
+        loop {
+            if column >= n_columns {
+                row += 1;
+                column = 0;
+            }
+            let cell = GridCell::new(row, column);
+            if !self.cells.iter().any(|c| c.overlaps(&cell)) {
+                self.cursor = (row, column + 1);
+                return cell;
+            }
+            column += 1;
+        }
+    }
+
+    /// Returns the sizing of every column track, including the implicit ones.
+    fn column_tracks(&self) -> Vec<SizingDimensions> {
+        let n = self
+            .cells
+            .iter()
+            .map(|c| c.column + c.column_span)
+            .fold(self.style.columns.len(), usize::max);
+        Self::tracks(&self.style.columns, n)
+    }
+
+    /// Returns the sizing of every row track, including the implicit ones.
+    fn row_tracks(&self) -> Vec<SizingDimensions> {
+        let n = self
+            .cells
+            .iter()
+            .map(|c| c.row + c.row_span)
+            .fold(self.style.rows.len(), usize::max);
+        Self::tracks(&self.style.rows, n)
+    }
+
+    fn tracks(defined: &[Sizing], n: usize) -> Vec<SizingDimensions> {
+        (0..n)
+            .map(|i| SizingDimensions::from(defined.get(i).copied().unwrap_or_default()))
+            .collect()
+    }
+
+    /// Returns the content size of each column track, based on the children widths.
+    fn column_contents(&self, n: usize) -> Vec<f64> {
+        let sizes = self
+            .children
+            .nodes
+            .iter()
+            .zip(&self.cells)
+            .map(|(node, cell)| (cell.column, cell.column_span, node.outer_width()));
+        track_contents(sizes, n, self.style.column_gap)
+    }
+
+    /// Returns the content size of each row track, based on the children heights.
+    fn row_contents(&self, n: usize) -> Vec<f64> {
+        let sizes = self
+            .children
+            .nodes
+            .iter()
+            .zip(&self.cells)
+            .map(|(node, cell)| (cell.row, cell.row_span, node.outer_height()));
+        track_contents(sizes, n, self.style.row_gap)
+    }
+
+    /// Resolves the column tracks against the final width, and resizes the children to their cells.
+    pub(crate) fn grow_children_width(&mut self, current_width: f64) {
+        let tracks = self.column_tracks();
+        let contents = self.column_contents(tracks.len());
+        let gaps = gaps(tracks.len(), self.style.column_gap);
+        let usable_width = current_width - self.style.padding.x();
+        let available = usable_width - gaps;
+        self.column_widths = resolve_tracks(&tracks, &contents, Some(available));
+
+        for (child, cell) in self.children.nodes.iter_mut().zip(&self.cells) {
+            let alignment = child.alignment(self.style.alignment);
+            child.resize_width_in_cell(
+                span_size(
+                    &self.column_widths,
+                    cell.column,
+                    cell.column_span,
+                    self.style.column_gap,
+                ),
+                alignment,
+            );
+        }
+        self.children.propagate_width_growth();
+        self.floating.grow_width(usable_width);
+    }
+
+    /// Resolves the row tracks against the final height, and resizes the children to their cells.
+    pub(crate) fn grow_children_height(&mut self, current_height: f64) {
+        let tracks = self.row_tracks();
+        let contents = self.row_contents(tracks.len());
+        let gaps = gaps(tracks.len(), self.style.row_gap);
+        let usable_height = current_height - self.style.padding.y();
+        let available = usable_height - gaps;
+        self.row_heights = resolve_tracks(&tracks, &contents, Some(available));
+
+        for (child, cell) in self.children.nodes.iter_mut().zip(&self.cells) {
+            let alignment = child.alignment(self.style.alignment);
+            child.resize_height_in_cell(
+                span_size(
+                    &self.row_heights,
+                    cell.row,
+                    cell.row_span,
+                    self.style.row_gap,
+                ),
+                alignment,
+            );
+        }
+        self.children.do_grow_height();
+        self.floating.grow_height(usable_height);
+    }
+
+    /// Positions the children within their cells, following their alignment
+    /// on both axes. Called after all sizing calculations are complete.
+    pub(crate) fn position_children(&mut self, offsets: (f64, f64), size: (f64, f64)) {
+        let column_offsets = track_offsets(
+            &self.column_widths,
+            offsets.0 + self.style.padding.left,
+            self.style.column_gap,
+        );
+        let row_offsets = track_offsets(
+            &self.row_heights,
+            offsets.1 + self.style.padding.top,
+            self.style.row_gap,
+        );
+        for (child, cell) in self.children.nodes.iter_mut().zip(&self.cells) {
+            let alignment = child.alignment(self.style.alignment);
+            let width = span_size(
+                &self.column_widths,
+                cell.column,
+                cell.column_span,
+                self.style.column_gap,
+            );
+            let height = span_size(
+                &self.row_heights,
+                cell.row,
+                cell.row_span,
+                self.style.row_gap,
+            );
+            child.set_position(
+                column_offsets[cell.column] + align_in(alignment, width - child.outer_width()),
+                row_offsets[cell.row] + align_in(alignment, height - child.outer_height()),
+            );
+        }
+        self.floating
+            .position((offsets.0, offsets.1, size.0, size.1));
+    }
+}
+
+/// Returns the cumulative size of the gaps between `n` tracks.
+fn gaps(n: usize, gap: f64) -> f64 {
+    n.saturating_sub(1) as f64 * gap
+}
+
+/// Returns the size of a cell spanning `span` tracks from `start`, gaps included.
+fn span_size(sizes: &[f64], start: usize, span: usize, gap: f64) -> f64 {
+    sizes[start..start + span].iter().sum::<f64>() + gaps(span, gap)
+}
+
+/// Returns the offset of a child within its cell, given the space it leaves free.
+fn align_in(alignment: Alignment, free: f64) -> f64 {
+    match alignment {
+        Alignment::Start | Alignment::Stretch => 0.0,
+        Alignment::Center => free / 2.0,
+        Alignment::End => free,
+    }
+}
+
+/// Returns the starting offset of each track.
+fn track_offsets(sizes: &[f64], start: f64, gap: f64) -> Vec<f64> {
+    sizes
+        .iter()
+        .scan(start, |offset, size| {
+            let current = *offset;
+            *offset += size + gap;
+            Some(current)
+        })
+        .collect()
+}
+
+/// ### Computes the content size of each track from the size of the children.
+/// Children spanning a single track are accounted for first, then children
+/// spanning multiple tracks evenly enlarge the tracks they don't fit in.
+fn track_contents(
+    children: impl Iterator<Item = (usize, usize, f64)> + Clone,
+    n: usize,
+    gap: f64,
+) -> Vec<f64> {
+    let mut contents = vec![0.0; n];
+    for (start, _, size) in children.clone().filter(|(_, span, _)| *span == 1) {
+        contents[start] = f64::max(contents[start], size);
+    }
+    for (start, span, size) in children.filter(|(_, span, _)| *span > 1) {
+        let missing = size - span_size(&contents, start, span, gap);
+        if missing > 0.0 {
+            contents[start..start + span]
+                .iter_mut()
+                .for_each(|c| *c += missing / span as f64);
+        }
+    }
+    contents
+}
+
+/// ### Resolves the final sizes of a set of tracks.
+/// Every track starts from its content size, clamped by its own sizing.
+///
+/// If some space is available, percentage tracks take their share of it, and
+/// what's left is given to the growable tracks by factor, or evenly taken away
+/// from the shrinkable tracks when overflowing.
+fn resolve_tracks(
+    tracks: &[SizingDimensions],
+    contents: &[f64],
+    available: Option<f64>,
+) -> Vec<f64> {
+    let mut sizes = tracks
+        .iter()
+        .zip(contents)
+        .map(|(track, content)| track.clamped(*content))
+        .collect::<Vec<_>>();
+    let Some(available) = available else {
+        return sizes;
+    };
+    for (size, track) in sizes.iter_mut().zip(tracks) {
+        if let Some(percent) = track.get_percent_of(available) {
+            *size = percent;
+        }
+    }
+
+    let mut remaining = available - sizes.iter().sum::<f64>();
+    let growing = remaining > 0.0;
+    let mut modifiable = (0..tracks.len())
+        .filter(|&i| {
+            if growing {
+                tracks[i].is_growable()
+            } else {
+                tracks[i].is_shrinkable()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    while remaining != 0.0 && !modifiable.is_empty() {
+        let factor = |i: usize| {
+            if growing {
+                tracks[i].get_grow_factor()
+            } else {
+                1.0
+            }
+        };
+        let total_factor = modifiable.iter().map(|&i| factor(i)).sum::<f64>();
+        if total_factor <= 0.0 {
+            break; // avoid infinite loops, means no progress can be made
+        }
+
+        let change_amount = remaining / total_factor;
+        let before = modifiable.len();
+        modifiable.retain(|&i| {
+            let wanted = change_amount * factor(i);
+            let size = tracks[i].clamped(sizes[i] + wanted);
+            let change = size - sizes[i];
+            sizes[i] = size;
+            remaining -= change;
+            change == wanted // tracks constrained by their min/max are done
+        });
+        if modifiable.len() == before {
+            break; // every track took its share, nothing left to distribute
+        }
+    }
+    sizes
+}
+
+impl<'frame, Color, CustomData> KaolinElement<'frame, Color, CustomData>
+    for Grid<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    fn inherit_color(&mut self, inherited_color: Color) {
+        self.inherited_color = Some(self.style.color.unwrap_or(inherited_color));
+    }
+
+    fn get_sizing_dimensions(&self) -> (SizingDimensions, SizingDimensions) {
+        let width = SizingDimensions::from(self.style.sizing.width);
+        let height = SizingDimensions::from(self.style.sizing.height);
+        (width, height)
+    }
+
+    fn starting_width(&self, sizing: &SizingDimensions) -> f64 {
+        let tracks = self.column_tracks();
+        let contents = self.column_contents(tracks.len());
+        let width = resolve_tracks(&tracks, &contents, None).iter().sum::<f64>()
+            + gaps(tracks.len(), self.style.column_gap)
+            + self.style.padding.x();
+        sizing.clamped(width)
+    }
+
+    fn fit_height_unbound(&mut self, _final_width: f64) -> f64 {
+        let tracks = self.row_tracks();
+        let contents = self.row_contents(tracks.len());
+        resolve_tracks(&tracks, &contents, None).iter().sum::<f64>()
+            + gaps(tracks.len(), self.style.row_gap)
+            + self.style.padding.y()
+    }
+
+    fn propagate_position(&mut self, offsets: (f64, f64), size: (f64, f64)) {
+        self.position_children(offsets, size);
+    }
+
+    fn render(
+        &self,
+        offsets: (f64, f64),
+        size: (f64, f64),
+    ) -> Box<dyn Iterator<Item = RenderCommand<Color, CustomData>> + '_> {
+        let id = self.id.as_deref().unwrap_or_default();
+        let self_command = RenderCommand::DrawRectangle {
+            id: id.to_string(),
+            x: offsets.0,
+            y: offsets.1,
+            width: size.0,
+            height: size.1,
+            color: self
+                .style
+                .background_color
+                .unwrap_or(Color::default_background_color()),
+            corner_radius: self.style.corner_radius,
+            border: self.style.border,
+        };
+        Box::new(core::iter::once(self_command).chain(self.children.render_nodes()))
+    }
+
+    fn render_floating(&self) -> Vec<FloatingLayer<'_, Color, CustomData>> {
+        let mut layers = self.children.render_floating();
+        layers.extend(self.floating.render());
+        layers
+    }
+
+    fn collect_bounding_boxes(&self, boxes: &mut BoundingBoxes) {
+        self.children.collect_bounding_boxes(boxes);
+        self.floating.collect_bounding_boxes(boxes);
+    }
+
+    fn as_container(
+        &mut self,
+    ) -> Option<&mut dyn KaolinContainerElement<'frame, Color, CustomData>> {
+        Some(self)
+    }
+}
+
+impl<'frame, Color, CustomData> KaolinContainerElement<'frame, Color, CustomData>
+    for Grid<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    #[allow(private_interfaces)]
+    fn add_child(&mut self, child: KaolinNode<'frame, Color, CustomData>) {
+        let cell = match self.next_cell.take() {
+            Some(cell) => cell,
+            None => self.next_free_cell(),
+        };
+        self.add_child_at(child, cell);
+    }
+
+    fn propagate_width_growth(&mut self, parent_width: f64) {
+        self.grow_children_width(parent_width);
+    }
+
+    fn propagate_height_growth(&mut self, parent_height: f64) {
+        self.grow_children_height(parent_height);
+    }
+}
+
+impl<'frame, Color, CustomData> ScopeContainer<'frame, Color, CustomData>
+    for Grid<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    fn children_color(&self) -> Option<Color> {
+        self.inherited_color
+    }
+
+    fn set_id(&mut self, id: Option<String>) {
+        self.id = id;
+    }
+
+    fn add_floating_child(
+        &mut self,
+        child: KaolinNode<'frame, Color, CustomData>,
+        floating: Floating,
+    ) {
+        self.next_cell = None;
+        self.floating.push(child, floating);
+    }
+}
@@ -1,20 +1,23 @@
 //! Internal representation of the layout elements.
 
+pub mod custom;
 pub(crate) mod flexbox;
+pub(crate) mod floating;
+pub(crate) mod grid;
 pub(crate) mod text;
 pub mod traits;
 pub use traits::*;
 
-use alloc::{
-    boxed::Box,
-    string::{String, ToString},
-    vec::Vec,
-};
-use uuid::Uuid;
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::ops::Range;
 
 use crate::{
-    commands::RenderCommand,
-    style::sizing::{PreferredSize, SizingDimensions},
+    commands::{BoundingBox, BoundingBoxes, RenderCommand},
+    style::{
+        layout::Alignment,
+        padding::Padding,
+        sizing::{PreferredSize, SizingDimensions},
+    },
 };
 
 /// A node in the layout tree
@@ -22,13 +25,18 @@ pub(crate) struct KaolinNode<'frame, Color, CustomData>
 where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
 {
-    #[allow(dead_code)]
-    id: String,
+    id: Option<String>,
     growable_width: bool,
     growable_height: bool,
-    shrinkable: bool,
+    shrinkable_width: bool,
+    shrinkable_height: bool,
     element: Box<dyn KaolinElement<'frame, Color, CustomData> + 'frame>,
+    /// Whether the element holds other nodes, rendering their commands with its own.
+    container: bool,
     sizing: (SizingDimensions, SizingDimensions),
+    align_self: Option<Alignment>,
+    aspect_ratio: Option<f64>,
+    margin: Padding,
     current_width: f64,
     current_height: f64,
     x: f64,
@@ -40,20 +48,27 @@ where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
 {
     pub(crate) fn new(
-        element: impl KaolinElement<'frame, Color, CustomData> + 'frame,
+        mut element: impl KaolinElement<'frame, Color, CustomData> + 'frame,
         id: Option<String>,
     ) -> Self {
-        let id = id.unwrap_or_else(|| Uuid::new_v4().to_string());
         let (width, height) = element.get_sizing_dimensions();
+        let container = element.as_container().is_some();
         KaolinNode {
             id,
             growable_width: element.default_growable_width(&width),
             growable_height: element.default_growable_height(&height),
-            shrinkable: element.default_shrinkable(&width),
+            shrinkable_width: element.default_shrinkable(&width),
+            shrinkable_height: element.default_shrinkable_height(&height),
             current_width: element.starting_width(&width),
             current_height: element.starting_height(&height),
             sizing: (width, height),
+            align_self: element.align_self(),
+            aspect_ratio: element
+                .aspect_ratio()
+                .filter(|ratio| ratio.is_finite() && *ratio > 0.0),
+            margin: element.margin(),
             element: Box::new(element),
+            container,
             x: 0.0,
             y: 0.0,
         }
@@ -72,6 +87,24 @@ where
         (w_factor, h_factor)
     }
 
+    /// Returns the cross axis alignment of the element, overriding the parent's
+    /// alignment if the element defines its own.
+    pub fn alignment(&self, parent_alignment: Alignment) -> Alignment {
+        self.align_self.unwrap_or(parent_alignment)
+    }
+
+    /// Returns the width of the element including its margins.
+    #[inline]
+    fn outer_width(&self) -> f64 {
+        self.current_width + self.margin.x()
+    }
+
+    /// Returns the height of the element including its margins.
+    #[inline]
+    fn outer_height(&self) -> f64 {
+        self.current_height + self.margin.y()
+    }
+
     /// ### Grows (or shrinks) the width of the element within the given limit.
     /// Returns the amount of growth/shrinkage actually applied.
     ///
@@ -85,27 +118,26 @@ where
                 self.growable_width = false; // No more growth possible
             }
             amount
-        } else if self.shrinkable && amount < 0.0 {
+        } else if self.shrinkable_width && amount < 0.0 {
             self.current_width += amount;
             if amount != limit {
-                self.shrinkable = false; // No more shrinking possible
+                self.shrinkable_width = false; // No more shrinking possible
             }
             amount
         } else {
             // no more change possible at all
             self.growable_width = false;
-            self.shrinkable = false;
+            self.shrinkable_width = false;
             0.0
         }
     }
 
-    /// ### Grows the height of the element within the given limit.
-    /// Returns the amount of growth actually applied.
+    /// ### Grows (or shrinks) the height of the element within the given limit.
+    /// Returns the amount of growth/shrinkage actually applied.
     ///
     /// The returned value differs from the input value only if the element was
-    /// constrained by its maximum size.
+    /// constrained by its minimum or maximum size.
     fn grow_height(&mut self, limit: f64) -> f64 {
-        debug_assert!(limit > 0.0, "Height can only grow, never shrink");
         let amount = self.sizing.1.clamped(self.current_height + limit) - self.current_height;
         if self.growable_height && amount > 0.0 {
             self.current_height += amount;
@@ -113,30 +145,136 @@ where
                 self.growable_height = false; // No more growth possible
             }
             amount
+        } else if self.shrinkable_height && amount < 0.0 {
+            self.current_height += amount;
+            if amount != limit {
+                self.shrinkable_height = false; // No more shrinking possible
+            }
+            amount
         } else {
+            // no more change possible at all
+            self.growable_height = false;
+            self.shrinkable_height = false;
             0.0
         }
     }
 
+    /// ### Stretches the width of the element up to the given size, margins included.
+    /// Used by [`Alignment::Stretch`](crate::style::layout::Alignment::Stretch),
+    /// this ignores whether the element is growable, but it is still constrained
+    /// by its minimum and maximum size. Never shrinks the element.
+    fn stretch_width(&mut self, size: f64) {
+        let size = size - self.margin.x();
+        self.current_width = self.current_width.max(self.sizing.0.clamped(size));
+    }
+
+    /// ### Stretches the height of the element up to the given size.
+    /// See [`KaolinNode::stretch_width`].
+    fn stretch_height(&mut self, size: f64) {
+        if self.aspect_ratio.is_some() {
+            return; // the height follows the width
+        }
+        let size = size - self.margin.y();
+        self.current_height = self.current_height.max(self.sizing.1.clamped(size));
+    }
+
+    /// ### Resizes the width of the element, within its minimum and maximum size.
+    /// Used by containers that set the size of their children directly, like grid cells.
+    /// The given size includes the margins.
+    fn resize_width(&mut self, size: f64) {
+        self.current_width = self.sizing.0.clamped(size - self.margin.x());
+    }
+
+    /// ### Resizes the height of the element, within its minimum and maximum size.
+    /// See [`KaolinNode::resize_width`].
+    fn resize_height(&mut self, size: f64) {
+        if self.aspect_ratio.is_some() {
+            return; // the height follows the width
+        }
+        self.current_height = self.sizing.1.clamped(size - self.margin.y());
+    }
+
+    /// ### Resizes the width of the element to a cell of the given size, margins included.
+    /// Stretched and growable elements fill the cell, the others keep their
+    /// size unless they overflow it. Used by grid cells.
+    fn resize_width_in_cell(&mut self, size: f64, alignment: Alignment) {
+        if alignment == Alignment::Stretch || self.growable_width {
+            self.resize_width(size);
+        } else {
+            self.resize_width(size.min(self.outer_width()));
+        }
+    }
+
+    /// ### Resizes the height of the element to a cell of the given size, margins included.
+    /// See [`KaolinNode::resize_width_in_cell`].
+    fn resize_height_in_cell(&mut self, size: f64, alignment: Alignment) {
+        if alignment == Alignment::Stretch || self.growable_height {
+            self.resize_height(size);
+        } else {
+            self.resize_height(size.min(self.outer_height()));
+        }
+    }
+
     /// Fits the height of the element to its content.
+    ///
+    /// Elements with an aspect ratio derive their height from the final width
+    /// instead, and the height is final from then on.
     fn fit_height(&mut self, final_width: f64) {
         let height = self.sizing.1;
-        self.current_height = height.clamped(self.element.fit_height_unbound(final_width));
+        let content_height = self.element.fit_height_unbound(final_width);
+        self.current_height = match self.aspect_ratio {
+            Some(ratio) => {
+                self.growable_height = false;
+                self.shrinkable_height = false;
+                height.clamped(final_width / ratio)
+            }
+            None => height.clamped(content_height),
+        };
     }
 
     /// ### Sets the position of the element, and propagates the change to its children.
     /// This gets called after all sizing calculations are complete.
+    ///
+    /// The position is the one of the margin box, the element itself is drawn
+    /// inside of its margins.
     pub fn set_position(&mut self, x: f64, y: f64) {
+        let (x, y) = (x + self.margin.left, y + self.margin.top);
         self.x = x;
         self.y = y;
         self.element
             .propagate_position((x, y), (self.current_width, self.current_height));
     }
 
-    /// Renders the element and its children into a series of rendering commands.
+    /// ### Renders the element and its children into a series of rendering commands.
+    /// The id of the node is set on the commands of the element. Containers set
+    /// it on their own commands, the ones of their children being rendered by
+    /// their own nodes.
     pub fn render(&self) -> Box<dyn Iterator<Item = RenderCommand<Color, CustomData>> + '_> {
-        self.element
-            .render((self.x, self.y), (self.current_width, self.current_height))
+        let commands = self
+            .element
+            .render((self.x, self.y), (self.current_width, self.current_height));
+        match &self.id {
+            Some(id) if !self.container => Box::new(commands.map(move |mut command| {
+                command.set_id(id);
+                command
+            })),
+            _ => commands,
+        }
+    }
+
+    /// Collects the final bounding box of the element if it has an id, along
+    /// with the ones of its children.
+    fn collect_bounding_boxes(&self, boxes: &mut BoundingBoxes) {
+        if let Some(id) = &self.id {
+            let bounding_box = BoundingBox {
+                x: self.x,
+                y: self.y,
+                width: self.current_width,
+                height: self.current_height,
+            };
+            boxes.insert(id.clone(), bounding_box);
+        }
+        self.element.collect_bounding_boxes(boxes);
     }
 }
 
@@ -161,22 +299,75 @@ where
         self.nodes.push(node);
     }
 
-    /// Returns an array of mutable references of all growable children in the horizontal direction.
-    fn get_growable_children_w(&mut self) -> Vec<&mut KaolinNode<'frame, Color, CustomData>> {
-        self.nodes.iter_mut().filter(|c| c.growable_width).collect()
+    /// ### Resolves the width of the children of a line sized as a percentage of the parent.
+    /// Resolved children are final, they won't grow or shrink afterwards.
+    fn resolve_percent_widths(&mut self, line: Range<usize>, parent_width: f64) {
+        for node in self.nodes[line].iter_mut() {
+            if let Some(width) = node.sizing.0.get_percent_of(parent_width) {
+                node.current_width = width;
+                node.growable_width = false;
+                node.shrinkable_width = false;
+            }
+        }
     }
 
-    /// Returns an array of mutable references of all growable children in the vertical direction.
-    fn get_growable_children_h(&mut self) -> Vec<&mut KaolinNode<'frame, Color, CustomData>> {
-        self.nodes
+    /// ### Resolves the height of the children of a line sized as a percentage of the parent.
+    /// See [`KaolinNodes::resolve_percent_widths`].
+    fn resolve_percent_heights(&mut self, line: Range<usize>, parent_height: f64) {
+        for node in self.nodes[line].iter_mut() {
+            if node.aspect_ratio.is_some() {
+                continue; // the height follows the width
+            }
+            if let Some(height) = node.sizing.1.get_percent_of(parent_height) {
+                node.current_height = height;
+                node.growable_height = false;
+                node.shrinkable_height = false;
+            }
+        }
+    }
+
+    /// Returns an array of mutable references of all growable children of a line in the horizontal direction.
+    fn get_growable_children_w(
+        &mut self,
+        line: Range<usize>,
+    ) -> Vec<&mut KaolinNode<'frame, Color, CustomData>> {
+        self.nodes[line]
+            .iter_mut()
+            .filter(|c| c.growable_width)
+            .collect()
+    }
+
+    /// Returns an array of mutable references of all growable children of a line in the vertical direction.
+    fn get_growable_children_h(
+        &mut self,
+        line: Range<usize>,
+    ) -> Vec<&mut KaolinNode<'frame, Color, CustomData>> {
+        self.nodes[line]
             .iter_mut()
             .filter(|c| c.growable_height)
             .collect()
     }
 
-    /// Returns an array of mutable references of all shrinkable children.
-    fn get_shrinkable_children(&mut self) -> Vec<&mut KaolinNode<'frame, Color, CustomData>> {
-        self.nodes.iter_mut().filter(|c| c.shrinkable).collect()
+    /// Returns an array of mutable references of all shrinkable children of a line in the horizontal direction.
+    fn get_shrinkable_children_w(
+        &mut self,
+        line: Range<usize>,
+    ) -> Vec<&mut KaolinNode<'frame, Color, CustomData>> {
+        self.nodes[line]
+            .iter_mut()
+            .filter(|c| c.shrinkable_width)
+            .collect()
+    }
+
+    /// Returns an array of mutable references of all shrinkable children of a line in the vertical direction.
+    fn get_shrinkable_children_h(
+        &mut self,
+        line: Range<usize>,
+    ) -> Vec<&mut KaolinNode<'frame, Color, CustomData>> {
+        self.nodes[line]
+            .iter_mut()
+            .filter(|c| c.shrinkable_height)
+            .collect()
     }
 
     /// Looks for the smallest and second smallest widths among the children.
@@ -229,28 +420,109 @@ where
         (biggest, second_biggest)
     }
 
-    /// Returns the cumulative width of the child nodes.
+    /// Looks for the biggest and second biggest heights among the children.
+    /// Important to make sure all children shrink together.
+    fn get_biggest_heights(children: &Vec<&mut KaolinNode<Color, CustomData>>) -> (f64, f64) {
+        let mut biggest = 0.0;
+        let mut second_biggest = 0.0;
+        for node in children {
+            if node.current_height > biggest {
+                second_biggest = biggest;
+                biggest = node.current_height;
+            } else if node.current_height > second_biggest && node.current_height < biggest {
+                second_biggest = node.current_height;
+            }
+        }
+        (biggest, second_biggest)
+    }
+
+    /// Returns the cumulative width of the child nodes, margins included.
     fn get_cumulative_width(&self) -> f64 {
-        self.nodes.iter().map(|c| c.current_width).sum()
+        self.nodes.iter().map(|c| c.outer_width()).sum()
     }
 
-    /// Returns the maximum width of the child nodes.
+    /// Returns the maximum width of the child nodes, margins included.
     fn get_max_width(&self) -> f64 {
         self.nodes
             .iter()
-            .fold(0.0, |acc, c| acc.max(c.current_width))
+            .fold(0.0, |acc, c| acc.max(c.outer_width()))
     }
 
-    /// Returns the cumulative height of the child nodes.
+    /// Returns the cumulative height of the child nodes, margins included.
     fn get_cumulative_height(&self) -> f64 {
-        self.nodes.iter().map(|c| c.current_height).sum()
+        self.nodes.iter().map(|c| c.outer_height()).sum()
     }
 
-    /// Returns the maximum height of the child nodes.
+    /// Returns the maximum height of the child nodes, margins included.
     fn get_max_height(&self) -> f64 {
         self.nodes
             .iter()
-            .fold(0.0, |acc, c| acc.max(c.current_height))
+            .fold(0.0, |acc, c| acc.max(c.outer_height()))
+    }
+
+    /// Returns the cumulative width of the child nodes, margins included within a line.
+    fn get_line_cumulative_width(&self, line: Range<usize>) -> f64 {
+        self.nodes[line].iter().map(|c| c.outer_width()).sum()
+    }
+
+    /// Returns the maximum width of the child nodes, margins included within a line.
+    fn get_line_max_width(&self, line: Range<usize>) -> f64 {
+        self.nodes[line]
+            .iter()
+            .fold(0.0, |acc, c| acc.max(c.outer_width()))
+    }
+
+    /// Returns the cumulative height of the child nodes, margins included within a line.
+    fn get_line_cumulative_height(&self, line: Range<usize>) -> f64 {
+        self.nodes[line].iter().map(|c| c.outer_height()).sum()
+    }
+
+    /// Returns the maximum height of the child nodes, margins included within a line.
+    fn get_line_max_height(&self, line: Range<usize>) -> f64 {
+        self.nodes[line]
+            .iter()
+            .fold(0.0, |acc, c| acc.max(c.outer_height()))
+    }
+
+    /// ### Splits the child nodes into lines fitting the available main axis size.
+    /// Returns the ranges of node indices that make up each line.
+    ///
+    /// A line always holds at least one node, even if the node overflows on its own.
+    fn break_lines(&self, available: f64, gap: f64, horizontal: bool) -> Vec<Range<usize>> {
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for end in 1..self.nodes.len() {
+            if self.get_line_size(start..end + 1, available, gap, horizontal) > available {
+                lines.push(start..end);
+                start = end;
+            }
+        }
+        if start < self.nodes.len() {
+            lines.push(start..self.nodes.len());
+        }
+        lines
+    }
+
+    /// ### Returns the main axis size of a line, margins and gaps included.
+    /// Children sized as a percentage take their share of the available size
+    /// left by the gaps of the line, like once resolved.
+    fn get_line_size(&self, line: Range<usize>, available: f64, gap: f64, horizontal: bool) -> f64 {
+        let gaps = line.len().saturating_sub(1) as f64 * gap;
+        let parent_size = available - gaps;
+        let children = self.nodes[line].iter().map(|node| {
+            let (sizing, margin, size) = if horizontal {
+                (node.sizing.0, node.margin.x(), node.outer_width())
+            } else if node.aspect_ratio.is_some() {
+                return node.outer_height(); // the height follows the width
+            } else {
+                (node.sizing.1, node.margin.y(), node.outer_height())
+            };
+            match sizing.get_percent_of(parent_size) {
+                Some(percent) if parent_size.is_finite() => percent + margin,
+                _ => size,
+            }
+        });
+        children.sum::<f64>() + gaps
     }
 
     /// Returns an iterator over the child nodes.
@@ -258,6 +530,14 @@ where
         self.nodes.iter_mut()
     }
 
+    /// Returns an iterator over the child nodes within a line.
+    fn line_nodes(
+        &mut self,
+        line: Range<usize>,
+    ) -> impl Iterator<Item = &mut KaolinNode<'frame, Color, CustomData>> {
+        self.nodes[line].iter_mut()
+    }
+
     /// Propagates the width growth of the element to its children.
     pub fn propagate_width_growth(&mut self) {
         self.nodes().for_each(|c| {
@@ -285,6 +565,21 @@ where
         )
     }
 
+    /// Collects the final bounding boxes of the child nodes with an id, at any depth.
+    pub fn collect_bounding_boxes(&self, boxes: &mut BoundingBoxes) {
+        self.nodes
+            .iter()
+            .for_each(|node| node.collect_bounding_boxes(boxes));
+    }
+
+    /// Collects the floating layers nested within the child nodes.
+    pub fn render_floating(&self) -> Vec<FloatingLayer<'_, Color, CustomData>> {
+        self.nodes
+            .iter()
+            .flat_map(|node| node.element.render_floating())
+            .collect()
+    }
+
     /// Returns the number of gaps that need to be accounted for.
     pub fn gaps(&self) -> u32 {
         if self.nodes.is_empty() {
@@ -1,6 +1,10 @@
 use alloc::{
+    borrow::Cow,
     boxed::Box,
+    collections::BTreeMap,
+    format,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
 use typed_floats::tf64::Positive;
@@ -12,7 +16,9 @@ use crate::{
     kaolin::MeasureTextFnRef,
     style::{
         TextStyle,
+        layout::Alignment,
         sizing::{PreferredSize, SizingDimensions},
+        text::{TextAlignment, TextOverflow, WordBreak},
     },
 };
 
@@ -24,6 +30,7 @@ where
     content: String,
     style: TextStyle<Color>,
     lines: Vec<(usize, usize)>, // (start, end) indices of lines in content
+    truncated: BTreeMap<usize, String>, // shortened text of the lines that overflow
     measure_text: MeasureTextFnRef<Color>,
     inherited_color: Option<Color>, // measure text function
 }
@@ -42,6 +49,7 @@ where
             content: content.to_string(),
             style,
             lines,
+            truncated: BTreeMap::new(),
             measure_text,
             inherited_color: None,
         }
@@ -55,6 +63,14 @@ where
         }
     }
 
+    /// Returns the height of a line of text, following the line height of the style.
+    fn line_height(&self, line: &str) -> f64 {
+        let (_, measured) = self.measure_text(line);
+        self.style
+            .line_height
+            .resolve(measured, self.style.font_size)
+    }
+
     /// Calculates the preferred size of the text element, without wrapping.
     pub fn get_preferred_size(
         &self,
@@ -62,52 +78,138 @@ where
         typed_floats::tf64::PositiveFinite,
         typed_floats::tf64::PositiveFinite,
     ) {
-        let floats =
-            self.content
-                .lines()
-                .fold((0.0f64, 0.0f64), |(max_width, total_height), line| {
-                    let (width, height) = self.measure_text(line);
-                    (max_width.max(width), total_height + height)
-                });
+        // blank lines take up their height, but aren't paragraphs nor counted as lines
+        let max_lines = self.style.max_lines.unwrap_or(usize::MAX);
+        let (mut width, mut height, mut paragraphs) = (0.0f64, 0.0f64, 0usize);
+        let mut blank_height = 0.0; // of the blank lines since the last paragraph
+        for line in self.content.lines() {
+            if trim_breakable(line).is_empty() {
+                blank_height += self.line_height(line);
+                continue;
+            }
+            if paragraphs == max_lines {
+                blank_height = 0.0;
+                break;
+            }
+            let (line_width, _) = self.measure_text(&displayed(line));
+            width = width.max(line_width);
+            height += blank_height + self.line_height(line);
+            blank_height = 0.0;
+            paragraphs += 1;
+        }
+        height += blank_height;
+        let spacing = self.style.paragraph_spacing * paragraphs.saturating_sub(1) as f64;
         (
-            typed_floats::tf64::PositiveFinite::new(floats.0).unwrap(),
-            typed_floats::tf64::PositiveFinite::new(floats.1).unwrap(),
+            typed_floats::tf64::PositiveFinite::new(width).unwrap(),
+            typed_floats::tf64::PositiveFinite::new(height + spacing).unwrap(),
         )
     }
 
     /// Calculates the minimum size the text can wrap to without overflowing.
+    /// Text breaking within words can shrink down to its widest grapheme,
+    /// and text shortened with an ellipsis down to the ellipsis itself.
     pub fn get_minimum_size(
         &self,
     ) -> (
         typed_floats::tf64::PositiveFinite,
         typed_floats::tf64::PositiveFinite,
     ) {
-        let floats = self.content.split_whitespace().fold(
-            (0.0f64, 0.0f64),
-            |(min_width, min_height), word| {
-                let (width, height) = self.measure_text(word);
-                (min_width.max(width), min_height.max(height))
-            },
-        );
+        let breakable: Vec<Cow<str>> = match self.style.word_break {
+            WordBreak::Normal => self
+                .segments()
+                .map(|(_, segment)| displayed(trim_breakable(segment)))
+                .collect(),
+            WordBreak::BreakAll | WordBreak::BreakWord => self
+                .content
+                .graphemes(true)
+                .filter(|grapheme| !grapheme.trim().is_empty())
+                .map(Cow::Borrowed)
+                .collect(),
+        };
+        let mut floats =
+            breakable
+                .iter()
+                .fold((0.0f64, 0.0f64), |(min_width, min_height), word| {
+                    let (width, _) = self.measure_text(word);
+                    (min_width.max(width), min_height.max(self.line_height(word)))
+                });
+        if self.style.overflow.ellipsizes() {
+            floats.0 = floats.0.min(self.measure_text("…").0);
+        }
         (
             typed_floats::tf64::PositiveFinite::new(floats.0).unwrap(),
             typed_floats::tf64::PositiveFinite::new(floats.1).unwrap(),
         )
     }
 
+    /// True if the line ending at the given index is the last one of its paragraph.
+    fn ends_paragraph(&self, end: usize) -> bool {
+        trim_breakable(&self.content[end..]).is_empty() || self.breaks_paragraph(end)
+    }
+
+    /// True if the line ending at the given index is followed by another paragraph.
+    fn breaks_paragraph(&self, end: usize) -> bool {
+        let rest = &self.content[end..];
+        !trim_breakable(rest).is_empty()
+            && rest
+                .chars()
+                .take_while(|c| c.is_whitespace())
+                .any(|c| c == '\n')
+    }
+
+    /// Splits a line into the pieces to draw, with their x offset within the element.
+    /// Justified lines are split into words, other lines are kept whole.
+    fn align_line<'a>(
+        &self,
+        line: &'a str,
+        ends_paragraph: bool,
+        width: f64,
+    ) -> Vec<(&'a str, f64)> {
+        let (line_width, _) = self.measure_text(line);
+        let free_space = (width - line_width).max(0.0);
+        match self.style.text_align {
+            TextAlignment::Left => vec![(line, 0.0)],
+            TextAlignment::Center => vec![(line, free_space / 2.0)],
+            TextAlignment::Right => vec![(line, free_space)],
+            TextAlignment::Justify => {
+                // non-breaking spaces are kept within words
+                let words: Vec<&str> = line
+                    .split(is_breakable_space)
+                    .filter(|word| !word.is_empty())
+                    .collect();
+                if ends_paragraph || words.len() < 2 {
+                    return vec![(line, 0.0)];
+                }
+                let widths: Vec<f64> = words.iter().map(|word| self.measure_text(word).0).collect();
+                let spacing =
+                    (width - widths.iter().sum::<f64>()).max(0.0) / (words.len() - 1) as f64;
+                let mut x = 0.0;
+                words
+                    .into_iter()
+                    .zip(widths)
+                    .map(|(word, word_width)| {
+                        let word_x = x;
+                        x += word_width + spacing;
+                        (word, word_x)
+                    })
+                    .collect()
+            }
+        }
+    }
+
     /// Wraps the text to fit within the specified width.
     pub fn wrap_text(&mut self, current_width: f64) -> f64 {
         // what we should actually do is find the positions of newlines
         // for each line either split at the last whitespace if the line is too long or at the newline
         // save all the absolute positions of the splitpoints for rendering later
 
+        self.lines.clear();
         let mut newlines = self.content.match_indices('\n').map(|(i, _)| i);
         let mut maybe_newline = newlines.next();
         let word_indices = self
-            .content
-            .unicode_word_indices()
+            .break_indices(current_width)
+            .into_iter()
             .skip(1) // the first word has to be in the first line duh
-            .map(|(i, _)| i)
             .chain(core::iter::once(self.content.len())); // end of the content as last "next word index"
 
         // the current start of the slice
@@ -116,45 +218,243 @@ where
         let mut prev_last = 0;
         // the start of the previous word, if we wrap that's the new start
         let mut prev_word_start = 0;
-        // accumulated height
-        let mut total_height = 0.0;
         for next_word_start in word_indices {
-            if let Some(next_newline) = maybe_newline
-                && next_word_start >= next_newline
-            {
-                self.lines.push((start, next_newline));
-                start = next_word_start;
-                prev_last = next_word_start;
+            // a newline before the next word ends the line, once what's before it is wrapped
+            let newline = maybe_newline.filter(|&newline| next_word_start >= newline);
+            let end = newline.unwrap_or(next_word_start);
+            // if we wrap, we should check the new word again
+            loop {
+                let slice = self.content[start..end].trim_end_matches(is_breakable_space);
+                let (width, _) = self.measure_text(&displayed(slice));
+
+                if start < prev_last && width > current_width {
+                    // it's a wrap!
+                    self.lines.push((start, prev_last)); // push the wrapped line
+                    start = prev_word_start; // reset start to the previous word start
+                } else {
+                    // no wrap, set where we ended up with and go to next word
+                    prev_last = start + slice.len();
+                    break;
+                }
+            }
+            if let Some(newline) = newline {
+                self.lines.push((start, newline));
+                // the other newlines before the next word end blank lines
                 maybe_newline = newlines.next();
-            } else {
-                // if we wrap, we should check the new word again
-                loop {
-                    let slice = self.content[start..next_word_start].trim_end();
-                    let (width, height) = self.measure_text(slice);
-
-                    if start < prev_last && width > current_width {
-                        // it's a wrap!
-                        self.lines.push((start, prev_last)); // push the wrapped line
-                        total_height += height; // accumulate height
-                        start = prev_word_start; // reset start to the previous word start
-                    } else {
-                        // no wrap, set where we ended up with and go to next word
-                        prev_last = start + slice.len();
-                        break;
-                    }
+                while let Some(blank) = maybe_newline.filter(|&blank| blank < next_word_start) {
+                    self.lines.push((blank, blank));
+                    maybe_newline = newlines.next();
                 }
+                start = next_word_start;
+                prev_last = next_word_start;
             }
             // update the previous word start
             prev_word_start = next_word_start;
         }
-        let last_slice = &self.content[start..].trim_end();
+        let last_slice = self.content[start..].trim_end_matches(is_breakable_space);
         if !last_slice.is_empty() {
             self.lines.push((start, last_slice.len() + start));
-            let (_, height) = self.measure_text(last_slice);
-            total_height += height;
         }
-        total_height
+        self.truncate_lines(current_width);
+        self.lines_height()
+    }
+
+    /// ### Splits the content at its line break opportunities.
+    /// Returns the start index of each segment, along with its text. Opportunities
+    /// follow the Unicode line breaking algorithm (UAX #14), e.g. after spaces,
+    /// hyphens and soft hyphens, or between CJK ideographs.
+    fn segments(&self) -> impl Iterator<Item = (usize, &str)> {
+        let mut start = 0;
+        unicode_linebreak::linebreaks(&self.content).map(move |(end, _)| {
+            let segment = (start, &self.content[start..end]);
+            start = end;
+            segment
+        })
+    }
+
+    /// Returns the indices where wrapped lines may start, following the word break of the style.
+    fn break_indices(&self, width: f64) -> Vec<usize> {
+        match self.style.word_break {
+            WordBreak::Normal => self.segments().map(|(start, _)| start).collect(),
+            WordBreak::BreakAll => self
+                .content
+                .grapheme_indices(true)
+                .filter(|(_, grapheme)| !grapheme.trim().is_empty())
+                .map(|(i, _)| i)
+                .collect(),
+            // only the words wider than the element are split
+            WordBreak::BreakWord => self
+                .segments()
+                .flat_map(|(start, segment)| {
+                    if self.measure_text(&displayed(trim_breakable(segment))).0 > width {
+                        segment
+                            .grapheme_indices(true)
+                            .filter(|(_, grapheme)| !grapheme.trim().is_empty())
+                            .map(|(i, _)| start + i)
+                            .collect()
+                    } else {
+                        vec![start]
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// ### Cuts the wrapped lines to `max_lines`, and shortens the lines that don't fit.
+    /// Blank lines aren't counted, and the ones before the first cut line are dropped.
+    /// With an ellipsis, the last visible line of a cut text shows as much of the
+    /// rest of its paragraph as fits, on a single line.
+    fn truncate_lines(&mut self, width: f64) {
+        let max_lines = self.style.max_lines.unwrap_or(usize::MAX);
+        let mut counted = 0;
+        let first_cut = self.lines.iter().position(|&line| {
+            counted += usize::from(!self.is_blank(line));
+            counted > max_lines
+        });
+        let cut = first_cut.is_some();
+        if let Some(first_cut) = first_cut {
+            let kept = self.lines[..first_cut]
+                .iter()
+                .rposition(|&line| !self.is_blank(line))
+                .map_or(0, |last| last + 1);
+            self.lines.truncate(kept);
+        }
+        if !self.style.overflow.ellipsizes() {
+            self.truncated.clear();
+            return;
+        }
+        let last = self.lines.len().saturating_sub(1);
+        self.truncated = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter_map(|(index, &(start, end))| {
+                if cut && index == last {
+                    // the rest of the paragraph, up to the first hard break
+                    let paragraph = self.content[start..].lines().next().unwrap_or_default();
+                    let rest: Vec<&str> = paragraph
+                        .split(is_breakable_space)
+                        .filter(|word| !word.is_empty())
+                        .collect();
+                    Some((
+                        index,
+                        self.ellipsize(&displayed(&rest.join(" ")), width, true),
+                    ))
+                } else {
+                    let line = displayed(trim_breakable(&self.content[start..end]));
+                    let (line_width, _) = self.measure_text(&line);
+                    (line_width > width).then(|| (index, self.ellipsize(&line, width, false)))
+                }
+            })
+            .collect();
+    }
+
+    /// Shortens a line with an ellipsis to fit within the width, following the overflow
+    /// of the style. Lines followed by `cut` text always get an ellipsis, and
+    /// the ellipsis alone is returned if nothing else fits.
+    fn ellipsize(&self, line: &str, width: f64, cut: bool) -> String {
+        if !cut && self.measure_text(line).0 <= width {
+            return line.to_string();
+        }
+        // the byte index of each grapheme, and the end of the line
+        let indices: Vec<usize> = line
+            .grapheme_indices(true)
+            .map(|(i, _)| i)
+            .chain(core::iter::once(line.len()))
+            .collect();
+        let graphemes = indices.len() - 1;
+        // keeps the given number of graphemes around the ellipsis
+        let shorten = |kept: usize| {
+            let (head, tail) = match self.style.overflow {
+                TextOverflow::StartEllipsis => (0, kept),
+                TextOverflow::MiddleEllipsis => (kept.div_ceil(2), kept / 2),
+                _ => (kept, 0),
+            };
+            format!(
+                "{}…{}",
+                line[..indices[head]].trim_end_matches(is_breakable_space),
+                line[indices[graphemes - tail]..].trim_start_matches(is_breakable_space)
+            )
+        };
+        // binary search of the most graphemes that fit, all of them if cut after
+        let most = if cut {
+            graphemes
+        } else {
+            graphemes.saturating_sub(1)
+        };
+        let (mut low, mut high) = (0, most);
+        while low < high {
+            let middle = (low + high).div_ceil(2);
+            if self.measure_text(&shorten(middle)).0 <= width {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+        shorten(low)
+    }
+
+    /// Returns the text to draw for a wrapped line, shortened if it overflows.
+    fn line(&self, index: usize) -> Cow<'_, str> {
+        match self.truncated.get(&index) {
+            Some(truncated) => Cow::Borrowed(truncated),
+            None => {
+                let (start, end) = self.lines[index];
+                displayed(trim_breakable(&self.content[start..end]))
+            }
+        }
+    }
+
+    /// True if the wrapped line has nothing to draw, like the ones between paragraphs.
+    fn is_blank(&self, (start, end): (usize, usize)) -> bool {
+        trim_breakable(&self.content[start..end]).is_empty()
+    }
+
+    /// Returns the height of the wrapped lines, with the spacing between paragraphs.
+    /// Blank lines take up a line height, without spacing.
+    fn lines_height(&self) -> f64 {
+        let last = self.lines.len().saturating_sub(1);
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(index, &(start, end))| {
+                let spacing =
+                    if index < last && !self.is_blank((start, end)) && self.breaks_paragraph(end) {
+                        self.style.paragraph_spacing
+                    } else {
+                        0.0
+                    };
+                self.line_height(&self.line(index)) + spacing
+            })
+            .sum()
+    }
+}
+
+/// True for the whitespace lines can break at, unlike non-breaking spaces.
+fn is_breakable_space(c: char) -> bool {
+    c.is_whitespace() && !matches!(c, '\u{A0}' | '\u{2007}' | '\u{202F}')
+}
+
+/// Trims the whitespace lines can break at, keeping non-breaking spaces.
+fn trim_breakable(text: &str) -> &str {
+    text.trim_matches(is_breakable_space)
+}
+
+/// ### Returns the text to draw for a line.
+/// Soft hyphens and zero width spaces are hidden, except for a soft hyphen
+/// ending the line, drawn as a hyphen.
+fn displayed(line: &str) -> Cow<'_, str> {
+    if !line.contains(['\u{AD}', '\u{200B}']) {
+        return Cow::Borrowed(line);
+    }
+    let mut text: String = line
+        .chars()
+        .filter(|&c| c != '\u{AD}' && c != '\u{200B}')
+        .collect();
+    if line.trim_end_matches('\u{200B}').ends_with('\u{AD}') {
+        text.push('-');
     }
+    Cow::Owned(text)
 }
 
 impl<'frame, Color, CustomData> KaolinElement<'frame, Color, CustomData> for TextElement<Color>
@@ -166,7 +466,8 @@ where
         let width = SizingDimensions {
             min: self.get_minimum_size().0,
             preferred: PreferredSize::Fixed(pref_width),
-            max: pref_width.into(),
+            // stretched text gets the room for its alignment
+            max: Positive::new(f64::INFINITY).unwrap(),
         };
         let height = SizingDimensions {
             min: pref_height,
@@ -179,31 +480,53 @@ where
     fn render(
         &self,
         offsets: (f64, f64),
-        _size: (f64, f64),
+        size: (f64, f64),
     ) -> Box<dyn Iterator<Item = RenderCommand<Color, CustomData>> + '_> {
+        let color = self
+            .style
+            .color
+            .or(self.inherited_color)
+            .unwrap_or(Color::default_foreground_color());
         let mut current_y = offsets.1;
-        Box::new(self.lines.iter().filter_map(move |line_indices| {
-            let (start, end) = *line_indices;
-            let line = self.content[start..end].trim();
-            if line.is_empty() {
-                return None;
-            }
-            let (_, height) = self.measure_text(line);
-            let y = current_y;
-            current_y += height;
-
-            Some(RenderCommand::DrawText {
-                text: line.to_string(),
-                x: offsets.0,
-                y,
-                font_id: self.style.font_id,
-                font_size: self.style.font_size,
-                color: self
+        // the pieces of text with their position, lines being split when justified
+        let pieces = self
+            .lines
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &(start, end))| {
+                let line = self.line(index);
+                if line.is_empty() || self.is_blank((start, end)) {
+                    current_y += self.line_height(&line);
+                    return Vec::new();
+                }
+                // the text is centered vertically within its line
+                let (_, measured) = self.measure_text(&line);
+                let line_height = self
                     .style
-                    .color
-                    .or(self.inherited_color)
-                    .unwrap_or(Color::default_foreground_color()),
-            })
+                    .line_height
+                    .resolve(measured, self.style.font_size);
+                let y = current_y + (line_height - measured) / 2.0;
+                current_y += line_height;
+                if self.breaks_paragraph(end) {
+                    current_y += self.style.paragraph_spacing;
+                }
+                // shortened lines are never justified
+                let ends_paragraph =
+                    self.truncated.contains_key(&index) || self.ends_paragraph(end);
+                self.align_line(&line, ends_paragraph, size.0)
+                    .into_iter()
+                    .map(|(text, x)| (text.to_string(), offsets.0 + x, y))
+                    .collect()
+            });
+        Box::new(pieces.map(move |(text, x, y)| RenderCommand::DrawText {
+            id: String::new(),
+            text,
+            x,
+            y,
+            font_id: self.style.font_id,
+            font_size: self.style.font_size,
+            letter_spacing: self.style.letter_spacing,
+            color,
         }))
     }
 
@@ -215,6 +538,10 @@ where
         true
     }
 
+    fn default_shrinkable_height(&self, _sizing: &crate::style::sizing::SizingDimensions) -> bool {
+        false
+    }
+
     fn default_growable_height(&self, _sizing: &crate::style::sizing::SizingDimensions) -> bool {
         false
     }
@@ -223,6 +550,10 @@ where
         self.wrap_text(final_width)
     }
 
+    fn align_self(&self) -> Option<Alignment> {
+        self.style.align_self
+    }
+
     fn inherit_color(&mut self, inherited_color: Color) {
         self.inherited_color = Some(inherited_color);
     }
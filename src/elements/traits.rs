@@ -1,10 +1,20 @@
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String, vec::Vec};
 
+use crate::commands::BoundingBoxes;
 use crate::elements::KaolinNode;
 use crate::elements::RenderCommand;
+use crate::style::floating::Floating;
+use crate::style::layout::Alignment;
+use crate::style::padding::Padding;
 use crate::style::sizing::PreferredSize;
 use crate::style::sizing::SizingDimensions;
 
+/// The render commands of a floating element, along with its z-index.
+pub type FloatingLayer<'a, Color, CustomData> = (
+    i16,
+    Box<dyn Iterator<Item = RenderCommand<Color, CustomData>> + 'a>,
+);
+
 /// This trait represents a generic UI element in the Kaolin layout system.
 ///
 /// You can create your own elements by implementing this trait, and providing the necessary
@@ -26,6 +36,9 @@ where
 
     /// This function gets called to render the element, and should return an iterator
     /// over the render commands for the element (and its children if any).
+    ///
+    /// The ids of the commands can be left empty, the id of the element is set
+    /// on them once rendered.
     fn render(
         &self,
         offsets: (f64, f64),
@@ -37,11 +50,16 @@ where
     fn default_growable_width(&self, sizing: &SizingDimensions) -> bool {
         sizing.is_growable()
     }
-    /// whether or not the element starts as shrinkable, defaults to
+    /// whether or not the element starts as shrinkable (in width), defaults to
     /// [`SizingDimensions::is_shrinkable`]
     fn default_shrinkable(&self, sizing: &SizingDimensions) -> bool {
         sizing.is_shrinkable()
     }
+    /// whether or not the element starts as shrinkable in height, defaults to
+    /// [`SizingDimensions::is_shrinkable`]
+    fn default_shrinkable_height(&self, sizing: &SizingDimensions) -> bool {
+        sizing.is_shrinkable()
+    }
     /// whether or not the element starts as growable in height, defaults to
     /// [`SizingDimensions::is_growable`]
     fn default_growable_height(&self, sizing: &SizingDimensions) -> bool {
@@ -64,6 +82,26 @@ where
         }
     }
 
+    /// Overrides the cross axis alignment set by the parent's layout for this
+    /// element only. Defaults to `None`, following the parent.
+    fn align_self(&self) -> Option<Alignment> {
+        None
+    }
+
+    /// Ties the height of the element to its final width, as a ratio of
+    /// width / height. The resulting height is still clamped by the height's
+    /// minimum and maximum size. Defaults to `None`, sizing each axis independently.
+    /// Ratios that aren't finite and positive are ignored.
+    fn aspect_ratio(&self) -> Option<f64> {
+        None
+    }
+
+    /// The outer space around the element, taken into account by its parent
+    /// when sizing and positioning it. Defaults to no margin.
+    fn margin(&self) -> Padding {
+        Padding::default()
+    }
+
     /// This function should be overridden to give a absolute lower bound for the
     /// node's height, based on the element's content and final width.
     /// This function can also be used to handle side-effects of width finalization
@@ -81,6 +119,18 @@ where
     #[allow(unused_variables)]
     fn propagate_position(&mut self, offsets: (f64, f64), size: (f64, f64)) {}
 
+    /// This function should be overridden by elements holding floating elements
+    /// (at any depth), returning their render commands along with their z-index.
+    /// Floating elements are drawn after the normal flow, see [`crate::style::floating::Floating`].
+    fn render_floating(&self) -> Vec<FloatingLayer<'_, Color, CustomData>> {
+        Vec::new()
+    }
+
+    /// This function should be overridden by elements holding other nodes,
+    /// collecting the final bounding boxes of the nodes with an id (at any depth).
+    #[allow(unused_variables)]
+    fn collect_bounding_boxes(&self, boxes: &mut BoundingBoxes) {}
+
     /// This function can be overridden to inherit the color property from the parent element.
     /// Useful for elements like text, that should remain consistent with the parent if not otherwised specified.
     #[allow(unused_variables)]
@@ -123,3 +173,24 @@ where
     /// Propagates height growth to the container's children.
     fn propagate_height_growth(&mut self, parent_height: f64);
 }
+
+/// A container the children defined within a [`Scope`] are added to.
+///
+/// [`Scope`]: crate::kaolin::scope::Scope
+pub(crate) trait ScopeContainer<'frame, Color, CustomData>:
+    KaolinContainerElement<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    /// The color inherited by the children of the container, once known.
+    fn children_color(&self) -> Option<Color>;
+    /// Sets the id of the container, for its own render commands. The node of a
+    /// container can't tell them apart from the ones of its children.
+    fn set_id(&mut self, id: Option<String>);
+    /// Adds a child out of the normal flow, anchored to the container.
+    fn add_floating_child(
+        &mut self,
+        child: KaolinNode<'frame, Color, CustomData>,
+        floating: Floating,
+    );
+}
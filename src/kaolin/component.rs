@@ -0,0 +1,108 @@
+//! ## Components
+//! Reusable pieces of UI, added to a scope with [`Scope::component`] or
+//! [`Scope::add`].
+
+use alloc::string::String;
+
+use crate::{
+    elements::traits::ScopeContainer,
+    kaolin::scope::{KaolinScope, Scope},
+    style::{FlexStyle, KaolinColor},
+};
+
+/// A reusable piece of UI, rendered within its own container.
+///
+/// Components hold whatever data they need to render, and can declare the style
+/// and id of their container. The container is created by the scope the
+/// component is added to, and [`Component::render`] fills it.
+///
+/// Example:
+/// ```ignore
+/// struct Card<'a> {
+///     title: &'a str,
+///     body: &'a str,
+/// }
+///
+/// impl<Color: KaolinColor + 'static, CustomData> Component<Color, CustomData> for Card<'_> {
+///     fn render<'frame>(
+///         &self,
+///         k: KaolinScope<'frame, Color, CustomData>,
+///     ) -> KaolinScope<'frame, Color, CustomData> {
+///         k.text(self.title, TextStyle::new().font_size(24.0))
+///             .text(self.body, TextStyle::new())
+///     }
+///
+///     fn style(&self) -> FlexStyle<Color> {
+///         FlexStyle::new().padding(Padding::all(8.0))
+///     }
+/// }
+///
+/// kaolin.draw(|k| k.add(Card { title: "Hello", body: "world" }))
+/// ```
+pub trait Component<Color, CustomData = !>
+where
+    Color: Default + Copy + PartialEq + KaolinColor,
+{
+    /// Adds the contents of the component to its container.
+    fn render<'frame>(
+        &self,
+        k: KaolinScope<'frame, Color, CustomData>,
+    ) -> KaolinScope<'frame, Color, CustomData>
+    where
+        CustomData: 'frame;
+
+    /// The style of the container of the component, unless overridden with
+    /// [`Scope::component_with`]. Defaults to [`FlexStyle::default`].
+    fn style(&self) -> FlexStyle<Color> {
+        FlexStyle::new()
+    }
+
+    /// The id of the container of the component, unless one was set with
+    /// [`Scope::id`] before adding it. Defaults to none.
+    fn id(&self) -> Option<String> {
+        None
+    }
+}
+
+#[allow(private_bounds)]
+impl<'frame, Color, CustomData: 'frame, Container> Scope<'frame, Color, CustomData, Container>
+where
+    Color: Default + Copy + PartialEq + KaolinColor + 'static,
+    Container: ScopeContainer<'frame, Color, CustomData>,
+{
+    /// ### Add a component, in its own container
+    ///
+    /// The container uses the style and id declared by the component, and is
+    /// placed like any other child of the scope. See [`Component`].
+    pub fn component(self, component: &impl Component<Color, CustomData>) -> Self {
+        self.component_with(component.style(), component)
+    }
+
+    /// ### Add a component, with a different style for its container
+    ///
+    /// Useful to size or position a component depending on where it is used.
+    ///
+    /// Example:
+    /// ```ignore
+    /// k.component_with(card.style().sizing(sizing!(grow!())), &card)
+    /// ```
+    pub fn component_with(
+        self,
+        style: FlexStyle<Color>,
+        component: &impl Component<Color, CustomData>,
+    ) -> Self {
+        let k = match component.id() {
+            Some(id) if !self.has_next_id() => self.id(id),
+            _ => self,
+        };
+        k.with(style, |k| component.render(k))
+    }
+
+    /// ### Add a component by value, in its own container
+    ///
+    /// See [`Scope::component`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, component: impl Component<Color, CustomData>) -> Self {
+        self.component(&component)
+    }
+}
@@ -0,0 +1,185 @@
+//! ## Layout DSL
+//! The [`kaolin!`](crate::kaolin!) macro, a tree syntax expanding to [`KaolinScope`](super::scope::KaolinScope) calls.
+
+/// Declares a layout tree, expanding to the equivalent [`KaolinScope`](crate::kaolin::scope::KaolinScope) calls.
+///
+/// The macro takes a scope, followed by `=>` and the children to add to it,
+/// and returns the scope:
+/// - `row(props) { ... }`, `column(props) { ... }` and `flex(props) { ... }`
+///   add a child container, laid out left to right, top to bottom, or with
+///   its style's direction. The parentheses can be left out without props.
+/// - `text(content, props)` adds a text element, with props being [`TextStyle`]
+///   setters (e.g. `font_size: 24.0`), or `style: TextStyle` as the first one.
+/// - `for pattern in iterator { ... }` adds children for each item.
+/// - `if condition { ... }` and `if condition { ... } else { ... }` add children
+///   conditionally, `if let` included.
+/// - `.method(args)` calls any other scope method, like widgets or components.
+///
+/// Container props are [`FlexStyle`] setters (e.g. `padding: Padding::all(8.0)`), along with:
+/// - `style: FlexStyle`, the base style, which must be the first prop.
+/// - `width` and `height`, setting one axis of the sizing with [`fit!`], [`fixed!`], [`grow!`] or [`percent!`].
+/// - the [`Layout`] setters (`gap`, `alignment`, `justification`, `wrap`...).
+/// - `id` and `focusable`, see [`KaolinScope::id`] and [`KaolinScope::focusable`].
+///
+/// Deep trees may need a higher `#![recursion_limit]`, as each node is expanded recursively.
+/// Invoke it by its path, as importing it would shadow the crate name.
+///
+/// Example:
+/// ```
+/// # use kaolin::{
+/// #     Kaolin, grow,
+/// #     style::{KaolinColor, TextStyle, layout::Alignment, padding::Padding},
+/// #     widgets::WidgetStyle,
+/// # };
+/// # #[derive(Default, Clone, Copy, PartialEq)]
+/// # struct Color;
+/// # impl KaolinColor for Color {}
+/// # let kaolin = Kaolin::new((800, 600), |text: &str, _: &TextStyle<Color>| {
+/// #     (text.len() as f64 * 10.0, 20.0)
+/// # });
+/// # let style = WidgetStyle::<Color>::new();
+/// struct Item {
+///     name: &'static str,
+///     enabled: bool,
+/// }
+/// let mut items = vec![Item { name: "Wi-Fi", enabled: true }];
+/// kaolin.draw::<()>(|k| {
+///     kaolin::kaolin!(k => column(width: grow!(), gap: 8.0, padding: Padding::all(16.0)) {
+///         text("Settings", font_size: 24.0)
+///         for item in items.iter_mut() {
+///             row(alignment: Alignment::Center) {
+///                 text(item.name)
+///                 .checkbox(item.name, "Enabled", &mut item.enabled, &style)
+///             }
+///         }
+///         if items.is_empty() {
+///             text("Nothing to see here")
+///         }
+///     })
+/// });
+/// ```
+///
+/// [`TextStyle`]: crate::style::TextStyle
+/// [`FlexStyle`]: crate::style::FlexStyle
+/// [`Layout`]: crate::style::layout::Layout
+/// [`KaolinScope::id`]: crate::kaolin::scope::KaolinScope::id
+/// [`KaolinScope::focusable`]: crate::kaolin::scope::KaolinScope::focusable
+/// [`fit!`]: crate::fit!
+/// [`fixed!`]: crate::fixed!
+/// [`grow!`]: crate::grow!
+/// [`percent!`]: crate::percent!
+#[macro_export]
+macro_rules! kaolin {
+    // children, one at a time, chained onto the scope
+    (@children $k:expr;) => {
+        $k
+    };
+    (@children $k:expr; row $(($($props:tt)*))? { $($body:tt)* } $($rest:tt)*) => {
+        $crate::kaolin!(@container $k; ($crate::style::FlexStyle::new().layout(
+            $crate::style::layout::Layout::new()
+                .direction($crate::style::layout::Direction::LeftToRight)
+        )); ($($($props)*)?); { $($body)* }; $($rest)*)
+    };
+    (@children $k:expr; column $(($($props:tt)*))? { $($body:tt)* } $($rest:tt)*) => {
+        $crate::kaolin!(@container $k; ($crate::style::FlexStyle::new().layout(
+            $crate::style::layout::Layout::new()
+                .direction($crate::style::layout::Direction::TopToBottom)
+        )); ($($($props)*)?); { $($body)* }; $($rest)*)
+    };
+    (@children $k:expr; flex $(($($props:tt)*))? { $($body:tt)* } $($rest:tt)*) => {
+        $crate::kaolin!(@container $k; ($crate::style::FlexStyle::new()); ($($($props)*)?); { $($body)* }; $($rest)*)
+    };
+    (@children $k:expr; text($content:expr, style: $style:expr $(, $key:ident: $value:expr)*) $($rest:tt)*) => {
+        $crate::kaolin!(@children $k.text($content, $style$(.$key($value))*); $($rest)*)
+    };
+    (@children $k:expr; text($content:expr $(, $key:ident: $value:expr)*) $($rest:tt)*) => {
+        $crate::kaolin!(@children $k.text($content, $crate::style::TextStyle::new()$(.$key($value))*); $($rest)*)
+    };
+    (@children $k:expr; for $pattern:pat in $($rest:tt)*) => {
+        $crate::kaolin!(@for $k; ($pattern); []; $($rest)*)
+    };
+    (@children $k:expr; if $($rest:tt)*) => {
+        $crate::kaolin!(@if $k; []; $($rest)*)
+    };
+    (@children $k:expr; .$method:ident($($args:tt)*) $($rest:tt)*) => {
+        $crate::kaolin!(@children $k.$method($($args)*); $($rest)*)
+    };
+
+    // container props, applied to the scope or to the style
+    (@container $k:expr; ($style:expr); (); { $($body:tt)* }; $($rest:tt)*) => {
+        $crate::kaolin!(@children $k.with($style, |k| $crate::kaolin!(@children k; $($body)*)); $($rest)*)
+    };
+    (@container $k:expr; ($style:expr); (style: $value:expr $(, $($props:tt)*)?); $($tail:tt)*) => {
+        $crate::kaolin!(@container $k; ($value); ($($($props)*)?); $($tail)*)
+    };
+    (@container $k:expr; ($style:expr); (id: $value:expr $(, $($props:tt)*)?); $($tail:tt)*) => {
+        $crate::kaolin!(@container $k.id($value); ($style); ($($($props)*)?); $($tail)*)
+    };
+    (@container $k:expr; ($style:expr); (focusable: $value:expr $(, $($props:tt)*)?); $($tail:tt)*) => {
+        $crate::kaolin!(@container $k.focusable($value); ($style); ($($($props)*)?); $($tail)*)
+    };
+    (@container $k:expr; ($style:expr); (width: $value:expr $(, $($props:tt)*)?); $($tail:tt)*) => {
+        $crate::kaolin!(@container $k; ({
+            let style = $style;
+            style.sizing($crate::style::sizing::BoxSizing { width: $value, ..style.sizing })
+        }); ($($($props)*)?); $($tail)*)
+    };
+    (@container $k:expr; ($style:expr); (height: $value:expr $(, $($props:tt)*)?); $($tail:tt)*) => {
+        $crate::kaolin!(@container $k; ({
+            let style = $style;
+            style.sizing($crate::style::sizing::BoxSizing { height: $value, ..style.sizing })
+        }); ($($($props)*)?); $($tail)*)
+    };
+    (@container $k:expr; ($style:expr); ($key:ident: $value:expr $(, $($props:tt)*)?); $($tail:tt)*) => {
+        $crate::kaolin!(@container $k; ($crate::kaolin!(@prop $style; $key; $value)); ($($($props)*)?); $($tail)*)
+    };
+
+    // layout setters go through the layout of the style, others directly to the style
+    (@prop $style:expr; direction; $value:expr) => { $crate::kaolin!(@layout $style; direction; $value) };
+    (@prop $style:expr; alignment; $value:expr) => { $crate::kaolin!(@layout $style; alignment; $value) };
+    (@prop $style:expr; justification; $value:expr) => { $crate::kaolin!(@layout $style; justification; $value) };
+    (@prop $style:expr; gap; $value:expr) => { $crate::kaolin!(@layout $style; gap; $value) };
+    (@prop $style:expr; wrap; $value:expr) => { $crate::kaolin!(@layout $style; wrap; $value) };
+    (@prop $style:expr; cross_gap; $value:expr) => { $crate::kaolin!(@layout $style; cross_gap; $value) };
+    (@prop $style:expr; line_alignment; $value:expr) => { $crate::kaolin!(@layout $style; line_alignment; $value) };
+    (@prop $style:expr; $key:ident; $value:expr) => {
+        $style.$key($value)
+    };
+    (@layout $style:expr; $key:ident; $value:expr) => {{
+        let style = $style;
+        style.layout(style.layout.$key($value))
+    }};
+
+    // loops, with the iterator collected up to the body
+    (@for $k:expr; ($pattern:pat); [$($iter:tt)*]; { $($body:tt)* } $($rest:tt)*) => {
+        $crate::kaolin!(@children
+            $k.each($($iter)*, |k, $pattern| $crate::kaolin!(@children k; $($body)*));
+            $($rest)*
+        )
+    };
+    (@for $k:expr; ($pattern:pat); [$($iter:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::kaolin!(@for $k; ($pattern); [$($iter)* $next]; $($rest)*)
+    };
+
+    // conditionals, with the condition collected up to the body
+    (@if $k:expr; [$($condition:tt)*]; { $($then:tt)* } else { $($otherwise:tt)* } $($rest:tt)*) => {
+        $crate::kaolin!(@children {
+            let k = $k;
+            if $($condition)* {
+                $crate::kaolin!(@children k; $($then)*)
+            } else {
+                $crate::kaolin!(@children k; $($otherwise)*)
+            }
+        }; $($rest)*)
+    };
+    (@if $k:expr; [$($condition:tt)*]; { $($then:tt)* } $($rest:tt)*) => {
+        $crate::kaolin!(@if $k; [$($condition)*]; { $($then)* } else {} $($rest)*)
+    };
+    (@if $k:expr; [$($condition:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::kaolin!(@if $k; [$($condition)* $next]; $($rest)*)
+    };
+
+    ($k:expr => $($tree:tt)*) => {
+        $crate::kaolin!(@children $k; $($tree)*)
+    };
+}
@@ -0,0 +1,188 @@
+//! ## Focus
+//! Keyboard (or rotary encoder, or buttons) focus, kept by [`Kaolin`](super::Kaolin)
+//! across frames. Elements are declared focusable with
+//! [`KaolinScope::focusable`](super::scope::KaolinScope::focusable).
+
+use alloc::{rc::Weak, string::String, vec::Vec};
+use core::cell::RefCell;
+
+use crate::commands::{BoundingBox, BoundingBoxes};
+
+/// A direction for spatial focus navigation.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FocusDirection {
+    /// Towards the top of the screen.
+    Up,
+    /// Towards the bottom of the screen.
+    Down,
+    /// Towards the left of the screen.
+    Left,
+    /// Towards the right of the screen.
+    Right,
+}
+
+/// The focus state shared between [`Kaolin`](super::Kaolin) and the scopes of a frame.
+#[derive(Default)]
+pub(crate) struct FocusState {
+    /// The id of the focused element, if any.
+    focused: Option<String>,
+    /// The focusable elements of the previous frame, in tab order.
+    order: Vec<String>,
+    /// The bounding boxes of the focusable elements of the previous frame.
+    bounding_boxes: BoundingBoxes,
+    /// The focusable elements declared so far in the current frame.
+    declared: Vec<String>,
+    /// Whether the focused element is activated in the current frame.
+    activated: bool,
+    /// The elements of the previous frame adjusted by the left and right arrows.
+    adjustable: Vec<String>,
+    /// The adjustable elements declared so far in the current frame.
+    declared_adjustable: Vec<String>,
+    /// The direction the focused element is adjusted towards in the current frame.
+    adjustment: Option<FocusDirection>,
+}
+
+pub(crate) type FocusStateRef = Weak<RefCell<FocusState>>;
+
+impl FocusState {
+    /// Returns the id of the focused element, if any.
+    pub(crate) fn focused(&self) -> Option<&str> {
+        self.focused.as_deref()
+    }
+
+    /// True if the element with the given id is focused.
+    pub(crate) fn is_focused(&self, id: &str) -> bool {
+        self.focused() == Some(id)
+    }
+
+    /// True if the element with the given id is focused and activated.
+    pub(crate) fn is_activated(&self, id: &str) -> bool {
+        self.activated && self.is_focused(id)
+    }
+
+    /// Activates the focused element for the next frame.
+    pub(crate) fn activate(&mut self) {
+        self.activated = true;
+    }
+
+    /// Returns the direction the element with the given id is adjusted towards,
+    /// if it is focused and adjusted in the current frame.
+    pub(crate) fn adjustment(&self, id: &str) -> Option<FocusDirection> {
+        self.adjustment.filter(|_| self.is_focused(id))
+    }
+
+    /// Declares an element adjusted by the left and right arrows in the current frame,
+    /// instead of moving the focus.
+    pub(crate) fn declare_adjustable(&mut self, id: String) {
+        self.declared_adjustable.push(id);
+    }
+
+    /// Declares a focusable element in the current frame, in tab order.
+    pub(crate) fn declare(&mut self, id: String) {
+        self.declared.push(id);
+    }
+
+    /// Focuses the element with the given id.
+    pub(crate) fn focus(&mut self, id: String) {
+        self.focused = Some(id);
+    }
+
+    /// Removes the focus from any element.
+    pub(crate) fn blur(&mut self) {
+        self.focused = None;
+    }
+
+    /// ### Moves the focus one element forward or backward in tab order, wrapping around.
+    /// Without a focused element, the first (or last, going backwards) one is focused.
+    pub(crate) fn step(&mut self, forward: bool) {
+        let n = self.order.len();
+        if n == 0 {
+            return;
+        }
+        let current = self
+            .focused
+            .as_ref()
+            .and_then(|focused| self.order.iter().position(|id| id == focused));
+        let next = match (current, forward) {
+            (Some(i), true) => (i + 1) % n,
+            (Some(i), false) => (i + n - 1) % n,
+            (None, true) => 0,
+            (None, false) => n - 1,
+        };
+        self.focused = Some(self.order[next].clone());
+    }
+
+    /// ### Moves the focus to the closest element in the given direction.
+    /// Elements are compared by the center of their bounding box, favoring the
+    /// ones aligned with the focused element. Without a focused element, the
+    /// first one in tab order is focused.
+    ///
+    /// Adjustable elements keep the focus on left and right, and are adjusted
+    /// in the next frame instead.
+    pub(crate) fn towards(&mut self, direction: FocusDirection) {
+        if matches!(direction, FocusDirection::Left | FocusDirection::Right)
+            && self
+                .focused
+                .as_ref()
+                .is_some_and(|focused| self.adjustable.contains(focused))
+        {
+            self.adjustment = Some(direction);
+            return;
+        }
+        let Some(current) = self
+            .focused
+            .as_ref()
+            .and_then(|focused| self.bounding_boxes.get(focused))
+        else {
+            return self.step(true);
+        };
+        let (x, y) = center(current);
+        let closest = self
+            .order
+            .iter()
+            .filter(|id| Some(*id) != self.focused.as_ref())
+            .filter_map(|id| {
+                let (other_x, other_y) = center(self.bounding_boxes.get(id)?);
+                // distance along the direction, and away from it
+                let (along, across) = match direction {
+                    FocusDirection::Up => (y - other_y, other_x - x),
+                    FocusDirection::Down => (other_y - y, other_x - x),
+                    FocusDirection::Left => (x - other_x, other_y - y),
+                    FocusDirection::Right => (other_x - x, other_y - y),
+                };
+                (along > 0.0).then_some((id, along + 2.0 * across.abs()))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+        if let Some((id, _)) = closest {
+            self.focused = Some(id.clone());
+        }
+    }
+
+    /// ### Concludes a frame, keeping its focusable elements for navigation.
+    /// The focus is lost if the focused element wasn't drawn, and activations
+    /// and adjustments only last a single frame.
+    pub(crate) fn end_frame(&mut self, bounding_boxes: &BoundingBoxes) {
+        self.activated = false;
+        self.adjustment = None;
+        self.adjustable = core::mem::take(&mut self.declared_adjustable);
+        self.order = core::mem::take(&mut self.declared);
+        self.bounding_boxes = self
+            .order
+            .iter()
+            .filter_map(|id| Some((id.clone(), *bounding_boxes.get(id)?)))
+            .collect();
+        if let Some(focused) = &self.focused
+            && !self.order.contains(focused)
+        {
+            self.focused = None;
+        }
+    }
+}
+
+/// Returns the center of a bounding box.
+fn center(bounding_box: &BoundingBox) -> (f64, f64) {
+    (
+        bounding_box.x + bounding_box.width / 2.0,
+        bounding_box.y + bounding_box.height / 2.0,
+    )
+}
@@ -0,0 +1,43 @@
+//! ## Element ids
+//! Helpers for giving stable ids to elements, see [`KaolinScope::id`](super::scope::KaolinScope::id).
+
+use alloc::{format, string::String};
+use core::hash::{Hash, Hasher};
+
+/// Creates an element id from any hashable key, for elements that don't have
+/// a meaningful name on their own, like the items of a list.
+///
+/// The id is the same across frames and runs for the same key.
+///
+/// ```
+/// # use kaolin::kaolin::id::hashed_id;
+/// assert_eq!(hashed_id(&("item", 3)), hashed_id(&("item", 3)));
+/// assert_ne!(hashed_id(&("item", 3)), hashed_id(&("item", 4)));
+/// ```
+pub fn hashed_id(key: &impl Hash) -> String {
+    let mut hasher = FnvHasher::default();
+    key.hash(&mut hasher);
+    format!("#{:016x}", hasher.finish())
+}
+
+/// FNV-1a hasher, which unlike the default hashers is not seeded randomly.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
@@ -22,20 +22,79 @@
 use alloc::{
     boxed::Box,
     rc::{Rc, Weak},
+    string::String,
 };
+use core::cell::RefCell;
 
 use crate::{
-    commands::RenderCommands,
-    elements::flexbox::FlexBox,
-    fixed, sizing,
-    style::{FlexStyle, TextStyle},
+    commands::{RenderCommand, RenderCommands},
+    elements::{flexbox::FlexBox, traits::ScopeContainer},
+    fixed,
+    kaolin::{
+        focus::{FocusDirection, FocusState, FocusStateRef},
+        pointer::{InputState, InputStateRef, Pointer},
+        state::{State, StateStore, StateStoreRef},
+    },
+    sizing,
+    style::{FlexStyle, TextStyle, border::Border},
 };
 
+pub mod component;
+mod dsl;
+pub mod focus;
+pub mod id;
+pub mod pointer;
 pub mod scope;
+pub mod state;
 
 pub type MeasureTextFnStatic<Color> = Box<dyn Fn(&str, &TextStyle<Color>) -> (f64, f64)>;
 pub(crate) type MeasureTextFnRef<Color> = Weak<MeasureTextFnStatic<Color>>;
 
+/// The state shared by [`Kaolin`] with the scopes of a frame.
+#[derive(Clone)]
+pub(crate) struct FrameContext {
+    pub(crate) input: InputStateRef,
+    pub(crate) state: StateStoreRef,
+    pub(crate) focus: FocusStateRef,
+}
+
+impl FrameContext {
+    /// Declares a focusable element, in tab order.
+    pub(crate) fn declare_focusable(&self, id: &str) {
+        if let Some(focus) = self.focus.upgrade() {
+            focus.borrow_mut().declare(String::from(id));
+        }
+    }
+
+    /// True if the element with the given id is focused.
+    pub(crate) fn is_focused(&self, id: &str) -> bool {
+        self.focus
+            .upgrade()
+            .is_some_and(|focus| focus.borrow().is_focused(id))
+    }
+
+    /// True if the element with the given id is focused and activated.
+    pub(crate) fn is_activated(&self, id: &str) -> bool {
+        self.focus
+            .upgrade()
+            .is_some_and(|focus| focus.borrow().is_activated(id))
+    }
+
+    /// Declares an element adjusted by the left and right arrows while focused.
+    pub(crate) fn declare_adjustable(&self, id: &str) {
+        if let Some(focus) = self.focus.upgrade() {
+            focus.borrow_mut().declare_adjustable(String::from(id));
+        }
+    }
+
+    /// Returns the direction the element with the given id is adjusted towards, if any.
+    pub(crate) fn adjustment(&self, id: &str) -> Option<FocusDirection> {
+        self.focus
+            .upgrade()
+            .and_then(|focus| focus.borrow().adjustment(id))
+    }
+}
+
 pub struct Kaolin<Color>
 where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
@@ -43,6 +102,10 @@ where
     width: f64,
     height: f64,
     measure_text: Rc<MeasureTextFnStatic<Color>>,
+    input: Rc<RefCell<InputState>>,
+    state: Rc<RefCell<StateStore>>,
+    focus: Rc<RefCell<FocusState>>,
+    focus_outline: Option<Border<Color>>,
 }
 
 impl<Color> Kaolin<Color>
@@ -60,9 +123,89 @@ where
             width: width as f64,
             height: height as f64,
             measure_text,
+            input: Rc::new(RefCell::new(InputState::default())),
+            state: Rc::new(RefCell::new(StateStore::default())),
+            focus: Rc::new(RefCell::new(FocusState::default())),
+            focus_outline: None,
         }
     }
 
+    /// Returns the id of the focused element, if any.
+    pub fn focused_id(&self) -> Option<String> {
+        self.focus.borrow().focused().map(String::from)
+    }
+
+    /// ### Focuses the element with the given id.
+    /// The element must be declared with [`scope::KaolinScope::focusable`] in
+    /// the next frame, or the focus is lost.
+    pub fn focus(&mut self, id: impl Into<String>) {
+        self.focus.borrow_mut().focus(id.into());
+    }
+
+    /// Removes the focus from any element.
+    pub fn blur(&mut self) {
+        self.focus.borrow_mut().blur();
+    }
+
+    /// ### Moves the focus to the next focusable element, in declaration order.
+    /// Wraps around after the last one, and focuses the first one if nothing was focused.
+    pub fn focus_next(&mut self) {
+        self.focus.borrow_mut().step(true);
+    }
+
+    /// ### Moves the focus to the previous focusable element, in declaration order.
+    /// Wraps around before the first one, and focuses the last one if nothing was focused.
+    pub fn focus_previous(&mut self) {
+        self.focus.borrow_mut().step(false);
+    }
+
+    /// ### Moves the focus to the closest focusable element in the given direction.
+    /// Based on the layout of the previous frame, the focus doesn't move if there
+    /// is no element that way. Focuses the first element if nothing was focused.
+    ///
+    /// Left and right adjust a focused slider instead, in the next frame.
+    pub fn focus_towards(&mut self, direction: FocusDirection) {
+        self.focus.borrow_mut().towards(direction);
+    }
+
+    /// ### Activates the focused element in the next frame, e.g. on Enter or Space.
+    /// The element is reported as clicked for that frame, see
+    /// [`scope::KaolinScope::is_clicked`].
+    pub fn activate(&mut self) {
+        self.focus.borrow_mut().activate();
+    }
+
+    /// ### Enables or disables the focus outline.
+    /// When enabled, a [`RenderCommand::DrawFocusOutline`] with the given border
+    /// is emitted last for the focused element.
+    pub fn show_focus_outline(&mut self, outline: Option<Border<Color>>) {
+        self.focus_outline = outline;
+    }
+
+    /// ### Returns a handle to the state of an element, kept between frames.
+    /// Useful to update the state from outside of the drawing function, e.g.
+    /// when handling events. See [`scope::KaolinScope::state`].
+    pub fn state<T: Default + 'static>(&self, id: &str) -> State<T> {
+        State::new(Rc::downgrade(&self.state), id)
+    }
+
+    /// ### Feeds the pointer state for the next frame.
+    /// Should be called before [`Kaolin::draw`], the last state set is kept
+    /// for the following frames.
+    ///
+    /// Within the drawing function, elements are hit-tested against the layout
+    /// of the previous frame, see [`scope::KaolinScope::is_hovered`].
+    pub fn set_pointer(&mut self, position: (f64, f64), pressed: bool) {
+        self.input
+            .borrow_mut()
+            .set_pointer(Pointer { position, pressed });
+    }
+
+    /// Returns the pointer state of the current frame.
+    pub fn pointer(&self) -> Pointer {
+        self.input.borrow().pointer
+    }
+
     pub fn draw<'frame, CustomData: 'frame>(
         &self,
         drawing_fn: impl FnOnce(
@@ -74,13 +217,43 @@ where
             height: fixed!(self.height),
         }));
         let measure_text_weak: MeasureTextFnRef<Color> = Rc::downgrade(&self.measure_text);
-        let mut scope = scope::KaolinScope::<_, CustomData>::new(flex, measure_text_weak);
+        let context = FrameContext {
+            input: Rc::downgrade(&self.input),
+            state: Rc::downgrade(&self.state),
+            focus: Rc::downgrade(&self.focus),
+        };
+        let mut scope =
+            scope::KaolinScope::<_, CustomData>::new(flex, measure_text_weak, context, None);
         scope = drawing_fn(scope);
 
-        let mut flex = scope.conclude();
+        let (mut flex, root_floating) = scope.conclude();
+        for (node, floating) in root_floating {
+            flex.add_floating_child(node, floating);
+        }
         flex.grow_children_width(self.width);
         flex.grow_children_height(self.height);
         flex.position_children((0.0, self.width, 0.0, self.height));
-        RenderCommands::new(flex)
+        let mut commands = RenderCommands::new(flex);
+        self.input.borrow_mut().end_frame(commands.bounding_boxes());
+        self.state
+            .borrow_mut()
+            .retain_drawn(commands.bounding_boxes());
+        let mut focus = self.focus.borrow_mut();
+        focus.end_frame(commands.bounding_boxes());
+        if let Some(border) = self.focus_outline
+            && let Some(id) = focus.focused()
+            && let Some(bounding_box) = commands.bounding_box(id)
+        {
+            commands.push(RenderCommand::DrawFocusOutline {
+                id: String::from(id),
+                x: bounding_box.x,
+                y: bounding_box.y,
+                width: bounding_box.width,
+                height: bounding_box.height,
+                border,
+            });
+        }
+        drop(focus);
+        commands
     }
 }
@@ -0,0 +1,112 @@
+//! ## Pointer input
+//! The pointer state fed to [`Kaolin`](super::Kaolin) each frame, used for hit-testing
+//! elements against the layout of the previous frame.
+
+use alloc::{collections::BTreeSet, rc::Weak, string::String};
+use core::cell::RefCell;
+
+use crate::commands::BoundingBoxes;
+
+/// The state of the pointer (mouse, touch...) for a frame.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct Pointer {
+    /// The absolute position of the pointer.
+    pub position: (f64, f64),
+    /// Whether the main button is held down (or the screen is being touched).
+    pub pressed: bool,
+}
+
+/// The input state shared between [`Kaolin`](super::Kaolin) and the scopes of a frame.
+#[derive(Default)]
+pub(crate) struct InputState {
+    /// The pointer state of the current frame.
+    pub(crate) pointer: Pointer,
+    /// The pointer state of the previous frame, used to detect clicks.
+    previous_pointer: Pointer,
+    /// The bounding boxes of the previous frame, by id.
+    bounding_boxes: BoundingBoxes,
+    /// The ids of the elements under the pointer when it was pressed, kept
+    /// until it is released. Only these elements can be clicked.
+    press_targets: BTreeSet<String>,
+}
+
+pub(crate) type InputStateRef = Weak<RefCell<InputState>>;
+
+impl InputState {
+    /// Updates the pointer state for the next frame.
+    pub(crate) fn set_pointer(&mut self, pointer: Pointer) {
+        self.pointer = pointer;
+    }
+
+    /// ### Concludes a frame, keeping its layout for hit-testing the next one.
+    /// The current pointer state becomes the previous one, so that clicks are
+    /// only reported for a single frame.
+    ///
+    /// The elements a press starts on are recorded against the layout the
+    /// frame was hit-tested with, before it gets replaced.
+    pub(crate) fn end_frame(&mut self, bounding_boxes: &BoundingBoxes) {
+        if !self.pointer.pressed {
+            self.press_targets.clear();
+        } else if !self.previous_pointer.pressed {
+            let (x, y) = self.pointer.position;
+            self.press_targets = self
+                .bounding_boxes
+                .iter()
+                .filter(|(_, bounding_box)| bounding_box.contains(x, y))
+                .map(|(id, _)| id.clone())
+                .collect();
+        }
+        self.previous_pointer = self.pointer;
+        self.bounding_boxes = bounding_boxes.clone();
+    }
+
+    /// True if the pointer is over the element with the given id, in the previous frame's layout.
+    pub(crate) fn is_hovered(&self, id: &str) -> bool {
+        let (x, y) = self.pointer.position;
+        self.bounding_boxes
+            .get(id)
+            .is_some_and(|bounding_box| bounding_box.contains(x, y))
+    }
+
+    /// Returns the pointer position relative to the element with the given id,
+    /// from (0, 0) at its top left corner to (1, 1) at its bottom right corner.
+    pub(crate) fn relative_position(&self, id: &str) -> Option<(f64, f64)> {
+        let (x, y) = self.pointer.position;
+        let bounding_box = self.bounding_boxes.get(id)?;
+        let relative = |position: f64, start: f64, size: f64| {
+            if size > 0.0 {
+                (position - start) / size
+            } else {
+                0.0
+            }
+        };
+        Some((
+            relative(x, bounding_box.x, bounding_box.width),
+            relative(y, bounding_box.y, bounding_box.height),
+        ))
+    }
+
+    /// True if the pointer is held down over the element with the given id.
+    pub(crate) fn is_pressed(&self, id: &str) -> bool {
+        self.pointer.pressed && self.is_hovered(id)
+    }
+
+    /// True if the pointer is held down after being pressed over the element
+    /// with the given id, wherever it is now.
+    pub(crate) fn is_dragged(&self, id: &str) -> bool {
+        match (self.previous_pointer.pressed, self.pointer.pressed) {
+            (_, false) => false,
+            (false, true) => self.is_hovered(id), // the press starts in this frame
+            (true, true) => self.press_targets.contains(id),
+        }
+    }
+
+    /// True if the pointer was released over the element with the given id in
+    /// this frame, after being pressed over the same element.
+    pub(crate) fn is_clicked(&self, id: &str) -> bool {
+        self.previous_pointer.pressed
+            && !self.pointer.pressed
+            && self.press_targets.contains(id)
+            && self.is_hovered(id)
+    }
+}
@@ -1,46 +1,383 @@
+use alloc::{string::String, vec::Vec};
+use core::hash::Hash;
+
 use crate::{
     elements::{
         KaolinNode,
+        custom::CustomElement,
         flexbox::FlexBox,
+        grid::Grid,
         text::TextElement,
-        traits::{KaolinContainerElement, KaolinElement},
+        traits::{KaolinElement, ScopeContainer},
+    },
+    kaolin::{FrameContext, MeasureTextFnRef, focus::FocusDirection, id::hashed_id, state::State},
+    style::{
+        FlexStyle, TextStyle,
+        floating::{AttachTo, Floating},
+        grid::{GridCell, GridStyle},
+        sizing::BoxSizing,
     },
-    kaolin::MeasureTextFnRef,
-    style::{FlexStyle, TextStyle},
 };
 
-pub struct KaolinScope<'frame, Color, CustomData>
+/// Floating elements attached to the root, collected while building the tree.
+type RootFloating<'frame, Color, CustomData> =
+    Vec<(KaolinNode<'frame, Color, CustomData>, Floating)>;
+
+/// ### The scope of a container, used to define its children
+///
+/// A scope is passed to the closure defining the contents of each container,
+/// and returned for chaining sibling elements. Flex containers get a
+/// [`KaolinScope`] and grids a [`GridScope`], both sharing the methods below.
+pub struct Scope<'frame, Color, CustomData, Container>
 where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor,
 {
-    flex: FlexBox<'frame, Color, CustomData>,
+    container: Container,
     measure_text: MeasureTextFnRef<Color>,
+    root_floating: RootFloating<'frame, Color, CustomData>,
+    context: FrameContext,
+    /// The id of the container the scope was created for, if any.
+    own_id: Option<String>,
+    /// The id of the next element added to the scope, see [`Scope::id`].
+    next_id: Option<String>,
+    /// Whether the next element added to the scope is focusable, see [`Scope::focusable`].
+    next_focusable: bool,
 }
 
+/// The scope of a flex container, see [`Scope::with`].
+pub type KaolinScope<'frame, Color, CustomData> =
+    Scope<'frame, Color, CustomData, FlexBox<'frame, Color, CustomData>>;
+
+/// The scope of a grid container, created by [`Scope::grid`].
+///
+/// Children are placed in the next free cell (left to right, then top to
+/// bottom), or in a specific [`GridCell`] with [`GridScope::at`].
+pub type GridScope<'frame, Color, CustomData> =
+    Scope<'frame, Color, CustomData, Grid<'frame, Color, CustomData>>;
+
 #[allow(private_bounds)]
-impl<'frame, Color, CustomData: 'frame> KaolinScope<'frame, Color, CustomData>
+impl<'frame, Color, CustomData: 'frame, Container> Scope<'frame, Color, CustomData, Container>
 where
     Color: Default + Copy + PartialEq + crate::style::KaolinColor + 'static,
     TextElement<Color>: KaolinElement<'frame, Color, CustomData>,
+    Container: ScopeContainer<'frame, Color, CustomData>,
 {
     /// Creates a new root scope, this is where the layout tree begins.
-    /// ### This should not be used externally, if you are looking for a way to create a new child element with its own scope, see [KaolinScope::with].
+    /// ### This should not be used externally, if you are looking for a way to create a new child element with its own scope, see [Scope::with].
     ///
-    /// A new scope is also created for each child flex container, iteratively
+    /// A new scope is also created for each child container, iteratively
     /// allowing for nested layouts.
     pub(super) fn new(
-        flex: FlexBox<'frame, Color, CustomData>,
+        container: Container,
         measure_text: MeasureTextFnRef<Color>,
+        context: FrameContext,
+        own_id: Option<String>,
     ) -> Self {
-        KaolinScope { flex, measure_text }
+        Scope {
+            container,
+            measure_text,
+            root_floating: Vec::new(),
+            context,
+            own_id,
+            next_id: None,
+            next_focusable: false,
+        }
     }
 
-    /// Conclude the current scope and return the flex container.
+    /// Conclude the current scope and return the container.
     /// This function is called internally when the component tree definition is
     /// completed, allowing the root element to access the finalized layout, consuming
     /// the scope in the process.
-    pub(super) fn conclude(self) -> FlexBox<'frame, Color, CustomData> {
-        self.flex
+    ///
+    /// The floating elements attached to the root found within the scope are
+    /// returned alongside, as they don't belong to the container.
+    pub(super) fn conclude(self) -> (Container, RootFloating<'frame, Color, CustomData>) {
+        (self.container, self.root_floating)
+    }
+
+    /// True if an id was set for the next element added to the scope.
+    pub(crate) fn has_next_id(&self) -> bool {
+        self.next_id.is_some()
+    }
+
+    /// Takes the id of the next element, declaring it if it was made focusable.
+    fn take_next_id(&mut self) -> Option<String> {
+        let id = self.next_id.take();
+        if core::mem::take(&mut self.next_focusable)
+            && let Some(id) = &id
+        {
+            self.context.declare_focusable(id);
+        }
+        id
+    }
+
+    /// ### Set the id of the next element added to the scope
+    ///
+    /// The id is carried into the render commands of the element, and its final
+    /// bounding box can be queried with [`RenderCommands::bounding_box`] after layout.
+    /// Ids should be unique, as only the last element with a given id is kept.
+    ///
+    /// Example:
+    /// ```ignore
+    /// let commands = kaolin.draw(|k| {
+    ///     k.id("sidebar").with(FlexStyle::new(), |k| k)
+    /// });
+    /// let sidebar = commands.bounding_box("sidebar");
+    /// ```
+    ///
+    /// [`RenderCommands::bounding_box`]: crate::commands::RenderCommands::bounding_box
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.next_id = Some(id.into());
+        self
+    }
+
+    /// ### Set the id of the next element added to the scope, from a hashable key
+    ///
+    /// Useful for elements without a meaningful name on their own, like list items.
+    /// See [`Scope::id`] and [`hashed_id`].
+    pub fn hashed_id(self, key: &impl Hash) -> Self {
+        self.id(hashed_id(key))
+    }
+
+    /// ### Set the id of the next element added to the scope, and make it focusable
+    ///
+    /// Focusable elements can receive the focus with [`Kaolin::focus_next`],
+    /// [`Kaolin::focus_previous`] and [`Kaolin::focus_towards`]. The tab order
+    /// is the order in which they are declared.
+    ///
+    /// Example:
+    /// ```ignore
+    /// k.focusable("ok").with(FlexStyle::new(), |k| {
+    ///     let color = if k.focused() { Color::YELLOW } else { Color::WHITE };
+    ///     k.text("OK", TextStyle::new().color(color))
+    /// })
+    /// ```
+    ///
+    /// [`Kaolin::focus_next`]: crate::kaolin::Kaolin::focus_next
+    /// [`Kaolin::focus_previous`]: crate::kaolin::Kaolin::focus_previous
+    /// [`Kaolin::focus_towards`]: crate::kaolin::Kaolin::focus_towards
+    pub fn focusable(mut self, id: impl Into<String>) -> Self {
+        self.next_focusable = true;
+        self.id(id)
+    }
+
+    /// ### Set the id of the next element added to the scope, and make it adjustable
+    ///
+    /// Adjustable elements are focusable, and keep the focus when
+    /// [`Kaolin::focus_towards`] goes left or right. The direction is reported
+    /// by [`Scope::adjustment`] in the next frame instead, e.g. to step a value.
+    ///
+    /// [`Kaolin::focus_towards`]: crate::kaolin::Kaolin::focus_towards
+    pub fn adjustable(self, id: impl Into<String>) -> Self {
+        let id = id.into();
+        self.context.declare_adjustable(&id);
+        self.focusable(id)
+    }
+
+    /// ### Add children for each item of an iterator
+    ///
+    /// Example:
+    /// ```ignore
+    /// k.each(&items, |k, item| k.text(&item.name, TextStyle::new()))
+    /// ```
+    pub fn each<I: IntoIterator>(
+        self,
+        items: I,
+        contents: impl FnMut(Self, I::Item) -> Self,
+    ) -> Self {
+        items.into_iter().fold(self, contents)
+    }
+
+    /// ### Add children only if the condition holds
+    ///
+    /// Example:
+    /// ```ignore
+    /// k.when(logged_in, |k| k.text("Welcome back!", TextStyle::new()))
+    /// ```
+    pub fn when(self, condition: bool, contents: impl FnOnce(Self) -> Self) -> Self {
+        if condition { contents(self) } else { self }
+    }
+
+    /// ### Add some children or others, depending on the condition
+    pub fn when_else(
+        self,
+        condition: bool,
+        contents: impl FnOnce(Self) -> Self,
+        otherwise: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        if condition {
+            contents(self)
+        } else {
+            otherwise(self)
+        }
+    }
+
+    /// ### Add children only if the option holds a value
+    ///
+    /// Example:
+    /// ```ignore
+    /// k.when_some(error.as_ref(), |k, error| k.text(error, TextStyle::new()))
+    /// ```
+    pub fn when_some<T>(self, option: Option<T>, contents: impl FnOnce(Self, T) -> Self) -> Self {
+        match option {
+            Some(value) => contents(self, value),
+            None => self,
+        }
+    }
+
+    /// ### Add children from a function of the scope, without breaking the chain
+    ///
+    /// Useful for `match` expressions, each arm returning the scope.
+    ///
+    /// Example:
+    /// ```ignore
+    /// k.apply(|k| match tab {
+    ///     Tab::Home => k.text("Home", TextStyle::new()),
+    ///     Tab::Settings => k.component(&settings),
+    /// })
+    /// ```
+    pub fn apply(self, contents: impl FnOnce(Self) -> Self) -> Self {
+        contents(self)
+    }
+
+    /// ### Check whether the element with the given id is focused
+    ///
+    /// Unlike the pointer state, the focus is up to date within the frame.
+    pub fn is_focused(&self, id: &str) -> bool {
+        self.context.is_focused(id)
+    }
+
+    /// ### Get the direction the element with the given id is adjusted towards
+    ///
+    /// Only left or right, for a single frame while the element is focused.
+    /// See [`Scope::adjustable`].
+    pub fn adjustment(&self, id: &str) -> Option<FocusDirection> {
+        self.context.adjustment(id)
+    }
+
+    /// ### Check whether the current element is focused
+    ///
+    /// See [`Scope::hovered`].
+    pub fn focused(&self) -> bool {
+        self.own_id.as_deref().is_some_and(|id| self.is_focused(id))
+    }
+
+    /// ### Check whether the pointer is over the element with the given id
+    ///
+    /// Elements are hit-tested against the layout of the previous frame, so the
+    /// result is always one frame late, and `false` for elements that weren't drawn.
+    /// Overlapping elements can all be hovered at the same time.
+    ///
+    /// Example:
+    /// ```ignore
+    /// let color = if k.is_hovered("button") { Color::GRAY } else { Color::WHITE };
+    /// k.id("button").with(FlexStyle::new().background_color(color), |k| k)
+    /// ```
+    pub fn is_hovered(&self, id: &str) -> bool {
+        self.context
+            .input
+            .upgrade()
+            .is_some_and(|input| input.borrow().is_hovered(id))
+    }
+
+    /// ### Check whether the pointer is held down over the element with the given id
+    ///
+    /// See [`Scope::is_hovered`].
+    pub fn is_pressed(&self, id: &str) -> bool {
+        self.context
+            .input
+            .upgrade()
+            .is_some_and(|input| input.borrow().is_pressed(id))
+    }
+
+    /// ### Check whether the element with the given id is being dragged
+    ///
+    /// An element is dragged while the pointer is held down after being
+    /// pressed over it, even once the pointer has left it.
+    /// See [`Scope::is_hovered`].
+    pub fn is_dragged(&self, id: &str) -> bool {
+        self.context
+            .input
+            .upgrade()
+            .is_some_and(|input| input.borrow().is_dragged(id))
+    }
+
+    /// ### Check whether the element with the given id was clicked
+    ///
+    /// An element is clicked when the pointer gets released over it, after
+    /// being pressed over it, or when it is focused and gets activated with
+    /// [`Kaolin::activate`]. See [`Scope::is_hovered`].
+    ///
+    /// [`Kaolin::activate`]: crate::kaolin::Kaolin::activate
+    pub fn is_clicked(&self, id: &str) -> bool {
+        self.context.is_activated(id)
+            || self
+                .context
+                .input
+                .upgrade()
+                .is_some_and(|input| input.borrow().is_clicked(id))
+    }
+
+    /// ### Get the pointer position relative to the element with the given id
+    ///
+    /// The position goes from (0, 0) at the top left corner of the element to
+    /// (1, 1) at its bottom right corner, and is outside of that range when the
+    /// pointer is outside of the element. `None` for elements that weren't drawn.
+    /// See [`Scope::is_hovered`].
+    pub fn relative_pointer(&self, id: &str) -> Option<(f64, f64)> {
+        self.context
+            .input
+            .upgrade()
+            .and_then(|input| input.borrow().relative_position(id))
+    }
+
+    /// ### Check whether the pointer is over the current element
+    ///
+    /// The current element is the container the scope was created for, and
+    /// it must have an id to be hit-tested. See [`Scope::is_hovered`].
+    ///
+    /// Example:
+    /// ```ignore
+    /// k.id("button").with(FlexStyle::new(), |k| {
+    ///     let label = if k.hovered() { "Click me!" } else { "Button" };
+    ///     k.text(label, TextStyle::new())
+    /// })
+    /// ```
+    pub fn hovered(&self) -> bool {
+        self.own_id.as_deref().is_some_and(|id| self.is_hovered(id))
+    }
+
+    /// ### Check whether the pointer is held down over the current element
+    ///
+    /// See [`Scope::hovered`].
+    pub fn pressed(&self) -> bool {
+        self.own_id.as_deref().is_some_and(|id| self.is_pressed(id))
+    }
+
+    /// ### Check whether the current element was clicked
+    ///
+    /// See [`Scope::hovered`] and [`Scope::is_clicked`].
+    pub fn clicked(&self) -> bool {
+        self.own_id.as_deref().is_some_and(|id| self.is_clicked(id))
+    }
+
+    /// ### Get a handle to the state of the element with the given id
+    ///
+    /// The state is kept between frames, starting from `T::default()`, and is
+    /// dropped once a frame is drawn without the element.
+    ///
+    /// Example:
+    /// ```ignore
+    /// let open = k.state::<bool>("menu");
+    /// if k.is_clicked("menu") {
+    ///     open.update(|open| *open = !*open);
+    /// }
+    /// k.id("menu").with(FlexStyle::new(), |k| {
+    ///     k.text(if open.get() { "Close" } else { "Open" }, TextStyle::new())
+    /// })
+    /// ```
+    pub fn state<T: Default + 'static>(&self, id: &str) -> State<T> {
+        State::new(self.context.state.clone(), id)
     }
 
     /// ### Create a child container within this element
@@ -66,55 +403,205 @@ where
             KaolinScope<'frame, Color, CustomData>,
         ) -> KaolinScope<'frame, Color, CustomData>,
     ) -> Self {
-        let mut child_flex = FlexBox::new(style);
-        let color = style
-            .color
-            .or(self.flex.inherited_color)
-            .unwrap_or(Color::default_foreground_color());
-        child_flex.inherit_color(color);
-        let child_scope = KaolinScope::new(child_flex, self.measure_text.clone());
-        let modified_scope = contents(child_scope);
-        let child_flex = modified_scope.conclude();
-        self.flex.add_child(KaolinNode::new(child_flex, None));
+        let id = self.take_next_id();
+        let flex = self.build(FlexBox::new(style), id.clone(), contents);
+        self.add_node(KaolinNode::new(flex, id), style.floating);
+        self
+    }
+
+    /// ### Create a child grid container within this element
+    ///
+    /// Works like [`Scope::with`], but the contents are placed in the
+    /// cells of a grid, defined by the column and row tracks of the [`GridStyle`].
+    /// See [`GridScope`] for how to place the children.
+    ///
+    /// Example:
+    /// ```ignore
+    /// k.grid(
+    ///     GridStyle::new().columns([fit!(), grow!()]).column_gap(8.0),
+    ///     |g| {
+    ///         g.text("Name", TextStyle::new()) // first column
+    ///             .with(FlexStyle::new(), |k| k) // second column
+    ///             .at(GridCell::new(1, 0).column_span(2))
+    ///             .text("Spanning both columns", TextStyle::new())
+    ///     },
+    /// )
+    /// ```
+    pub fn grid(
+        mut self,
+        style: GridStyle<Color>,
+        contents: impl FnOnce(
+            GridScope<'frame, Color, CustomData>,
+        ) -> GridScope<'frame, Color, CustomData>,
+    ) -> Self {
+        let id = self.take_next_id();
+        let grid = self.build(Grid::new(style), id.clone(), contents);
+        self.add_node(KaolinNode::new(grid, id), None);
         self
     }
 
     /// ### Add a text element to the current scope
     ///
     /// This function allows you to add a text element to the current scope.
-    /// The text element will be added as a child of the current flex container for which the scope was created.
+    /// The text element will be added as a child of the container for which the scope was created.
     ///
     /// The scope gets also passed through and returned for chaining sibling
     /// elements.
     ///
     /// Example:
     /// ```ignore
-    /// k.text("Hello, world!", TextStyle::new()) // new text element inside the flex container
+    /// k.text("Hello, world!", TextStyle::new()) // new text element inside the container
     /// ```
     pub fn text(mut self, content: &str, style: TextStyle<Color>) -> Self {
         let mut text_element = TextElement::new(content, style, self.measure_text.clone());
-        let color = self
-            .flex
-            .inherited_color
-            .unwrap_or(Color::default_foreground_color());
-        text_element.inherit_color(color);
-
-        self.flex.add_child(KaolinNode::new(text_element, None));
+        text_element.inherit_color(self.inherited_color());
+        let id = self.take_next_id();
+        self.add_node(KaolinNode::new(text_element, id), None);
         self
     }
 
     /// ### Add a custom element to the current scope
-    ////
+    ///
     /// This function allows you to add any element implementing the `KaolinElement` trait
-    /// to the current scope.
+    /// to the current scope, taking ownership of it. The element only needs to
+    /// live as long as the frame, and can own heap data.
     ///
     /// See [`KaolinElement`] for more details on how to create your own elements,
     /// and [`kaolin::renderers::embedded::image::Image`] for an example implementation.
-    pub fn with_element<Element: KaolinElement<'frame, Color, CustomData> + Copy + 'frame>(
+    pub fn add_element(
         mut self,
+        element: impl KaolinElement<'frame, Color, CustomData> + 'frame,
+    ) -> Self {
+        let id = self.take_next_id();
+        self.add_node(KaolinNode::new(element, id), None);
+        self
+    }
+
+    /// ### Add a copy of a custom element to the current scope
+    ///
+    /// See [`Scope::add_element`], which also accepts elements that aren't `Copy`.
+    pub fn with_element<Element: KaolinElement<'frame, Color, CustomData> + Copy + 'frame>(
+        self,
         element: &'frame Element,
     ) -> Self {
-        self.flex.add_child(KaolinNode::new(*element, None));
+        self.add_element(*element)
+    }
+
+    /// ### Add a custom element drawn by the renderer from a closure
+    ///
+    /// The element is sized like a container, and emits a single
+    /// [`RenderCommand::Custom`] with the data returned by the closure, given
+    /// the final (width, height) of the element. See [`CustomElement`].
+    ///
+    /// Example:
+    /// ```ignore
+    /// k.custom(sizing!(grow!(), fixed!(40.0)), move |(width, height)| {
+    ///     Sparkline::new(&samples, width, height)
+    /// })
+    /// ```
+    ///
+    /// [`RenderCommand::Custom`]: crate::commands::RenderCommand::Custom
+    pub fn custom(
+        self,
+        sizing: BoxSizing,
+        draw: impl Fn((f64, f64)) -> CustomData + 'frame,
+    ) -> Self {
+        self.add_element(CustomElement::new(sizing, draw))
+    }
+
+    /// The color inherited by the children of the container.
+    fn inherited_color(&self) -> Color {
+        self.container
+            .children_color()
+            .unwrap_or(Color::default_foreground_color())
+    }
+
+    /// Builds a child container and its contents, inheriting the parent's color.
+    /// Floating elements attached to the root found within are kept by this scope.
+    fn build<Child: ScopeContainer<'frame, Color, CustomData>>(
+        &mut self,
+        mut container: Child,
+        id: Option<String>,
+        contents: impl FnOnce(
+            Scope<'frame, Color, CustomData, Child>,
+        ) -> Scope<'frame, Color, CustomData, Child>,
+    ) -> Child {
+        container.inherit_color(self.inherited_color());
+        container.set_id(id.clone());
+        let scope = Scope::new(
+            container,
+            self.measure_text.clone(),
+            self.context.clone(),
+            id,
+        );
+        let (container, root_floating) = contents(scope).conclude();
+        self.root_floating.extend(root_floating);
+        container
+    }
+
+    /// Adds a child to the container, or out of the normal flow if floating.
+    fn add_node(
+        &mut self,
+        node: KaolinNode<'frame, Color, CustomData>,
+        floating: Option<Floating>,
+    ) {
+        match floating {
+            None => self.container.add_child(node),
+            Some(floating) if floating.attach_to == AttachTo::Root => {
+                self.root_floating.push((node, floating))
+            }
+            Some(floating) => self.container.add_floating_child(node, floating),
+        }
+    }
+}
+
+impl<'frame, Color, CustomData> KaolinScope<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor,
+{
+    /// True if the children of the current container are laid out horizontally.
+    pub fn has_horizontal_layout(&self) -> bool {
+        self.container.has_horizontal_layout()
+    }
+}
+
+#[allow(private_bounds)]
+impl<'frame, Color, CustomData: 'frame> GridScope<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + crate::style::KaolinColor + 'static,
+    TextElement<Color>: KaolinElement<'frame, Color, CustomData>,
+{
+    /// ### Place the next element added to the grid in a specific cell
+    ///
+    /// Any element can be placed this way, with its span included. Elements
+    /// added without a cell go in the next cell left free.
+    ///
+    /// Explicit cells may overlap each other: the overlapping elements are
+    /// then drawn on top of each other, in the order they were added.
+    ///
+    /// Example:
+    /// ```ignore
+    /// g.at(GridCell::new(0, 1).row_span(2))
+    ///     .text("Tall cell", TextStyle::new())
+    ///     .at(GridCell::new(2, 0))
+    ///     .grid(GridStyle::new(), |g| g) // nested grid
+    /// ```
+    pub fn at(mut self, cell: GridCell) -> Self {
+        self.container.place_next(cell);
         self
     }
+
+    /// ### Create a child container in a specific cell of the grid
+    ///
+    /// Shorthand for [`GridScope::at`] followed by [`Scope::with`].
+    pub fn cell(
+        self,
+        cell: GridCell,
+        style: FlexStyle<Color>,
+        contents: impl FnOnce(
+            KaolinScope<'frame, Color, CustomData>,
+        ) -> KaolinScope<'frame, Color, CustomData>,
+    ) -> Self {
+        self.at(cell).with(style, contents)
+    }
 }
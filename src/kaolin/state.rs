@@ -0,0 +1,97 @@
+//! ## Element state
+//! State kept between frames for the elements with an id, see [`KaolinScope::state`](super::scope::KaolinScope::state).
+
+use alloc::{boxed::Box, collections::BTreeMap, rc::Weak, string::String};
+use core::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    marker::PhantomData,
+};
+
+use crate::commands::BoundingBoxes;
+
+/// The state of all the elements, by element id and type.
+#[derive(Default)]
+pub(crate) struct StateStore {
+    entries: BTreeMap<(String, TypeId), Box<dyn Any>>,
+}
+
+pub(crate) type StateStoreRef = Weak<RefCell<StateStore>>;
+
+impl StateStore {
+    /// Evicts the state of the elements that weren't drawn in the last frame.
+    pub(crate) fn retain_drawn(&mut self, bounding_boxes: &BoundingBoxes) {
+        self.entries
+            .retain(|(id, _), _| bounding_boxes.contains_key(id));
+    }
+
+    /// Returns the state of the given type for an element, creating it if missing.
+    fn entry<T: Default + 'static>(&mut self, id: &str) -> &mut T {
+        self.entries
+            .entry((String::from(id), TypeId::of::<T>()))
+            .or_insert_with(|| Box::new(T::default()))
+            .downcast_mut()
+            .expect("the state is keyed by its type")
+    }
+}
+
+/// ### A handle to the state of an element, kept between frames.
+///
+/// The state starts from `T::default()`, and is dropped as soon as a frame is
+/// drawn without the element it belongs to. An element can hold one state of
+/// each type.
+///
+/// The handle can be kept and used outside of the drawing function, in which
+/// case the changes are visible from the next frame.
+pub struct State<T: Default + 'static> {
+    store: StateStoreRef,
+    id: String,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Default + 'static> State<T> {
+    pub(crate) fn new(store: StateStoreRef, id: &str) -> Self {
+        State {
+            store,
+            id: String::from(id),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the id of the element the state belongs to.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// ### Updates the state in place, returning the result of the given function.
+    /// If the [`Kaolin`](super::Kaolin) instance was dropped, the function is
+    /// applied to a default value that is not stored.
+    ///
+    /// The store is borrowed while the function runs, so it must not access
+    /// other states.
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        match self.store.upgrade() {
+            Some(store) => f(store.borrow_mut().entry(&self.id)),
+            None => f(&mut T::default()),
+        }
+    }
+
+    /// Replaces the state with the given value.
+    pub fn set(&self, value: T) {
+        self.update(|state| *state = value);
+    }
+
+    /// Returns a copy of the state.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.update(|state| state.clone())
+    }
+}
+
+impl<T: Default + 'static> Clone for State<T> {
+    fn clone(&self) -> Self {
+        State::new(self.store.clone(), &self.id)
+    }
+}
@@ -8,4 +8,5 @@ pub mod elements;
 pub mod kaolin;
 pub mod renderers;
 pub mod style;
+pub mod widgets;
 pub use kaolin::Kaolin;
@@ -16,7 +16,7 @@ use crate::{
 /// Implementation of KaolinElement for embedded-graphics Image
 ///
 /// This allows you to use embedded-graphics images as elements within a Kaolin layout.
-/// See [`KaolinScope::with_element`] for more details.
+/// See [`KaolinScope::add_element`] and [`KaolinScope::with_element`] for more details.
 impl<'frame, Color, I> KaolinElement<'frame, Color, Image<'frame, I>> for Image<'frame, I>
 where
     Color: PixelColor + Default + KaolinColor + From<<Color as PixelColor>::Raw> + 'static,
@@ -35,6 +35,11 @@ where
         )
     }
 
+    fn aspect_ratio(&self) -> Option<f64> {
+        let raw_size = self.bounding_box().size;
+        (raw_size.height > 0).then(|| raw_size.width as f64 / raw_size.height as f64)
+    }
+
     fn render(
         &self,
         offsets: (f64, f64),
@@ -1,10 +1,12 @@
 pub mod image;
 
+use alloc::{string::String, vec::Vec};
+
 use crate::{
-    commands::RenderCommand,
-    kaolin::{Kaolin, scope::KaolinScope},
+    commands::{BoundingBox, RenderCommand},
+    kaolin::{Kaolin, focus::FocusDirection, scope::KaolinScope},
     renderers::KaolinRenderer,
-    style::KaolinColor,
+    style::{KaolinColor, border::Border},
 };
 use embedded_graphics::{
     image::Image,
@@ -49,8 +51,9 @@ where
                 let dimensions = font
                     .get_rendered_dimensions(text, (0, 0).into(), VerticalPosition::Top)
                     .unwrap_or_else(|_| RenderedDimensions::empty());
+                let spacing = config.letter_spacing * text.chars().count().saturating_sub(1) as f64;
                 match dimensions.bounding_box {
-                    Some(box_) => (box_.size.width as f64, box_.size.height as f64),
+                    Some(box_) => (box_.size.width as f64 + spacing, box_.size.height as f64),
                     None => (0.0, 0.0),
                 }
             },
@@ -58,6 +61,47 @@ where
         Self { fonts, kaolin }
     }
 
+    /// Feeds the pointer state (e.g. from a touchscreen) for the next frame.
+    /// See [`Kaolin::set_pointer`].
+    pub fn set_pointer(&mut self, position: (f64, f64), pressed: bool) {
+        self.kaolin.set_pointer(position, pressed);
+    }
+
+    /// Moves the focus to the next focusable element, e.g. on a "next" button.
+    /// See [`Kaolin::focus_next`].
+    pub fn focus_next(&mut self) {
+        self.kaolin.focus_next();
+    }
+
+    /// Moves the focus to the previous focusable element.
+    /// See [`Kaolin::focus_previous`].
+    pub fn focus_previous(&mut self) {
+        self.kaolin.focus_previous();
+    }
+
+    /// Moves the focus in the given direction, e.g. on a directional pad.
+    /// See [`Kaolin::focus_towards`].
+    pub fn focus_towards(&mut self, direction: FocusDirection) {
+        self.kaolin.focus_towards(direction);
+    }
+
+    /// Activates the focused element, e.g. on a "select" button.
+    /// See [`Kaolin::activate`].
+    pub fn activate(&mut self) {
+        self.kaolin.activate();
+    }
+
+    /// Returns the id of the focused element, if any.
+    pub fn focused_id(&self) -> Option<String> {
+        self.kaolin.focused_id()
+    }
+
+    /// Enables or disables the focus outline.
+    /// See [`Kaolin::show_focus_outline`].
+    pub fn show_focus_outline(&mut self, outline: Option<Border<Color>>) {
+        self.kaolin.show_focus_outline(outline);
+    }
+
     /// Prepare to render onto a specific DrawTarget.
     /// This returns an intermediate structure that
     /// holds a mutable reference to the target, and implements [`KaolinRenderer`],
@@ -85,6 +129,135 @@ where
     target: Option<&'frame mut D>,
 }
 
+/// Converts the area of a [`RenderCommand::ScissorStart`] into a rectangle,
+/// clipped within the current one.
+fn scissor_rectangle(
+    current: &Rectangle,
+    (x, y, width, height): (f64, f64, f64, f64),
+) -> Rectangle {
+    let current = BoundingBox {
+        x: current.top_left.x as f64,
+        y: current.top_left.y as f64,
+        width: current.size.width as f64,
+        height: current.size.height as f64,
+    };
+    let area = BoundingBox {
+        x,
+        y,
+        width,
+        height,
+    }
+    .intersection(&current);
+    Rectangle::new(
+        Point::new(area.x as i32, area.y as i32),
+        Size::new(area.width as u32, area.height as u32),
+    )
+}
+
+impl<'frame, D, C> EmbeddedRendererFrame<'frame, D, C>
+where
+    D: DrawTarget<Color = C>,
+    C: PixelColor + Default + KaolinColor + 'static,
+{
+    /// Draws a single command onto the given target.
+    fn draw_command<T, I>(&self, command: RenderCommand<C, Image<'frame, I>>, target: &mut T)
+    where
+        T: DrawTarget<Color = C>,
+        I: ImageDrawable<Color = C> + Clone,
+    {
+        match command {
+            RenderCommand::DrawRectangle {
+                x,
+                y,
+                width,
+                height,
+                color,
+                border,
+                ..
+            } => {
+                let _ = Rectangle::new(
+                    Point::new(x as i32, y as i32),
+                    Size::new(width as u32, height as u32),
+                )
+                .into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .fill_color(color)
+                        .stroke_color(border.color)
+                        .stroke_width(border.width as u32)
+                        .stroke_alignment(embedded_graphics::primitives::StrokeAlignment::Inside)
+                        .build(),
+                )
+                .draw(target);
+            }
+            RenderCommand::DrawText {
+                text,
+                x,
+                y,
+                font_id,
+                letter_spacing,
+                color,
+                ..
+            } => {
+                let font = self
+                    .renderer
+                    .fonts
+                    .get(font_id as usize)
+                    .unwrap_or(self.renderer.fonts.first().unwrap());
+
+                if letter_spacing == 0.0 {
+                    let _ = font.render(
+                        text.as_str(),
+                        Point::new(x as i32, y as i32),
+                        VerticalPosition::Top,
+                        FontColor::Transparent(color),
+                        target,
+                    );
+                    return;
+                }
+                // the fonts have no spacing option, so each glyph is drawn on its own
+                let mut x = x;
+                for glyph in text.chars() {
+                    let Ok(dimensions) = font.render(
+                        glyph,
+                        Point::new(x as i32, y as i32),
+                        VerticalPosition::Top,
+                        FontColor::Transparent(color),
+                        &mut *target,
+                    ) else {
+                        break;
+                    };
+                    x += dimensions.advance.x as f64 + letter_spacing;
+                }
+            }
+            RenderCommand::Custom { x, y, data, .. } => {
+                let _ = data.translate(Point::new(x as i32, y as i32)).draw(target);
+            }
+            RenderCommand::DrawFocusOutline {
+                x,
+                y,
+                width,
+                height,
+                border,
+                ..
+            } => {
+                let _ = Rectangle::new(
+                    Point::new(x as i32, y as i32),
+                    Size::new(width as u32, height as u32),
+                )
+                .into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(border.color)
+                        .stroke_width(border.width as u32)
+                        .stroke_alignment(embedded_graphics::primitives::StrokeAlignment::Inside)
+                        .build(),
+                )
+                .draw(target);
+            }
+            RenderCommand::ScissorStart { .. } | RenderCommand::ScissorEnd => {}
+        }
+    }
+}
+
 impl<'frame, D, C, I> KaolinRenderer<'frame, C, Image<'frame, I>>
     for EmbeddedRendererFrame<'frame, D, C>
 where
@@ -101,58 +274,27 @@ where
         let target = self.target.take().unwrap();
         let commands = self.renderer.kaolin.draw(draw_fn);
         // println!("Drawing {:?} commands", commands);
+        let mut clips: Vec<Rectangle> = Vec::new();
         for command in commands {
             match command {
-                RenderCommand::DrawRectangle {
+                RenderCommand::ScissorStart {
                     x,
                     y,
                     width,
                     height,
-                    color,
-                    border,
                     ..
                 } => {
-                    let _ = Rectangle::new(
-                        Point::new(x as i32, y as i32),
-                        Size::new(width as u32, height as u32),
-                    )
-                    .into_styled(
-                        PrimitiveStyleBuilder::new()
-                            .fill_color(color)
-                            .stroke_color(border.color)
-                            .stroke_width(border.width as u32)
-                            .stroke_alignment(
-                                embedded_graphics::primitives::StrokeAlignment::Inside,
-                            )
-                            .build(),
-                    )
-                    .draw(target);
-                }
-                RenderCommand::DrawText {
-                    text,
-                    x,
-                    y,
-                    font_id,
-                    color,
-                    ..
-                } => {
-                    let font = self
-                        .renderer
-                        .fonts
-                        .get(font_id as usize)
-                        .unwrap_or(self.renderer.fonts.first().unwrap());
-
-                    let _ = font.render(
-                        text.as_str(),
-                        Point::new(x as i32, y as i32),
-                        VerticalPosition::Top,
-                        FontColor::Transparent(color),
-                        target,
-                    );
+                    // nested scissors only draw where all of their areas overlap
+                    let current = clips.last().copied().unwrap_or(target.bounding_box());
+                    clips.push(scissor_rectangle(&current, (x, y, width, height)));
                 }
-                RenderCommand::Custom { x, y, data, .. } => {
-                    let _ = data.translate(Point::new(x as i32, y as i32)).draw(target);
+                RenderCommand::ScissorEnd => {
+                    clips.pop();
                 }
+                command => match clips.last() {
+                    Some(area) => self.draw_command(command, &mut target.clipped(area)),
+                    None => self.draw_command(command, target),
+                },
             }
         }
     }
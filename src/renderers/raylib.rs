@@ -2,14 +2,16 @@ extern crate std;
 
 use std::{
     cell::RefCell,
+    iter::Peekable,
     rc::{Rc, Weak},
+    vec::Vec,
 };
 
 use crate::{
-    commands::RenderCommand,
-    kaolin::{Kaolin, scope::KaolinScope},
+    commands::{BoundingBox, RenderCommand},
+    kaolin::{Kaolin, focus::FocusDirection, scope::KaolinScope},
     renderers::KaolinRenderer,
-    style::{KaolinColor, TextStyle},
+    style::{KaolinColor, TextStyle, border::Border},
 };
 use raylib::{color::Color, prelude::*};
 
@@ -37,8 +39,9 @@ impl RaylibRenderer {
     ) -> (f64, f64) {
         let raylib = this.upgrade().unwrap();
         let raylib = raylib.borrow();
-        let len = raylib.measure_text(text, config.font_size as i32);
-        (len as f64, config.font_size as f64)
+        let len = raylib.measure_text(text, config.font_size as i32) as f64;
+        let spacing = config.letter_spacing * text.chars().count().saturating_sub(1) as f64;
+        (len + spacing, config.font_size as f64)
     }
 
     pub fn new(width: i32, height: i32) -> Self {
@@ -58,40 +61,174 @@ impl RaylibRenderer {
         }
     }
 
+    /// Enables or disables the focus outline, moved with tab and the arrow keys.
+    /// See [`Kaolin::show_focus_outline`].
+    pub fn show_focus_outline(&mut self, outline: Option<Border<Color>>) {
+        self.kaolin.show_focus_outline(outline);
+    }
+
     pub fn should_close(&self) -> bool {
         let raylib = self.raylib.borrow();
         raylib.window_should_close()
     }
 }
 
+/// Draws the commands up to the next scissor command, or until the end.
+fn draw_commands<CustomData>(
+    d: &mut impl RaylibDraw,
+    commands: &mut Peekable<impl Iterator<Item = RenderCommand<Color, CustomData>>>,
+    font: &WeakFont,
+) {
+    let is_drawing = |command: &RenderCommand<Color, CustomData>| {
+        !matches!(
+            command,
+            RenderCommand::ScissorStart { .. } | RenderCommand::ScissorEnd
+        )
+    };
+    while let Some(command) = commands.next_if(is_drawing) {
+        match command {
+            RenderCommand::DrawRectangle {
+                x,
+                y,
+                width,
+                height,
+                color,
+                ..
+            } => {
+                d.draw_rectangle(x as i32, y as i32, width as i32, height as i32, color);
+            }
+            RenderCommand::DrawText {
+                text,
+                x,
+                y,
+                color,
+                font_size,
+                letter_spacing,
+                ..
+            } if letter_spacing == 0.0 => {
+                d.draw_text(text.as_str(), x as i32, y as i32, font_size as i32, color);
+            }
+            RenderCommand::DrawText {
+                text,
+                x,
+                y,
+                color,
+                font_size,
+                letter_spacing,
+                ..
+            } => {
+                // the spacing of the default font is a tenth of its size
+                d.draw_text_ex(
+                    font,
+                    text.as_str(),
+                    Vector2::new(x as f32, y as f32),
+                    font_size,
+                    font_size / 10.0 + letter_spacing as f32,
+                    color,
+                );
+            }
+            RenderCommand::DrawFocusOutline {
+                x,
+                y,
+                width,
+                height,
+                border,
+                ..
+            } => {
+                d.draw_rectangle_lines_ex(
+                    Rectangle::new(x as f32, y as f32, width as f32, height as f32),
+                    border.width,
+                    border.color,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
 impl KaolinRenderer<Color> for RaylibRenderer {
     fn draw(&mut self, draw_fn: impl Fn(KaolinScope<Color>) -> KaolinScope<Color>) {
+        let (position, pressed, tab, shift, direction, activate) = {
+            let raylib = self.raylib.borrow();
+            let mouse = raylib.get_mouse_position();
+            let direction = [
+                (KeyboardKey::KEY_UP, FocusDirection::Up),
+                (KeyboardKey::KEY_DOWN, FocusDirection::Down),
+                (KeyboardKey::KEY_LEFT, FocusDirection::Left),
+                (KeyboardKey::KEY_RIGHT, FocusDirection::Right),
+            ]
+            .into_iter()
+            .find(|(key, _)| raylib.is_key_pressed(*key))
+            .map(|(_, direction)| direction);
+            (
+                (mouse.x as f64, mouse.y as f64),
+                raylib.is_mouse_button_down(MouseButton::MOUSE_BUTTON_LEFT),
+                raylib.is_key_pressed(KeyboardKey::KEY_TAB),
+                raylib.is_key_down(KeyboardKey::KEY_LEFT_SHIFT)
+                    || raylib.is_key_down(KeyboardKey::KEY_RIGHT_SHIFT),
+                direction,
+                raylib.is_key_pressed(KeyboardKey::KEY_ENTER)
+                    || raylib.is_key_pressed(KeyboardKey::KEY_SPACE),
+            )
+        };
+        self.kaolin.set_pointer(position, pressed);
+        match (tab, shift, direction) {
+            (true, false, _) => self.kaolin.focus_next(),
+            (true, true, _) => self.kaolin.focus_previous(),
+            (false, _, Some(direction)) => self.kaolin.focus_towards(direction),
+            _ => {}
+        }
+        if activate {
+            self.kaolin.activate();
+        }
         let commands = self.kaolin.draw(draw_fn);
         let mut raylib = self.raylib.borrow_mut();
         let mut d = raylib.begin_drawing(&self.thread);
-        for command in commands {
-            match command {
-                RenderCommand::DrawRectangle {
+        let screen = (d.get_screen_width() as f64, d.get_screen_height() as f64);
+        let font = d.get_font_default();
+        // nested clipping areas are intersected, and drawn in one scissor mode each
+        let mut clips: Vec<BoundingBox> = Vec::new();
+        let mut commands = commands.peekable();
+        loop {
+            match clips.last() {
+                Some(area) => {
+                    let mut s = d.begin_scissor_mode(
+                        area.x as i32,
+                        area.y as i32,
+                        area.width as i32,
+                        area.height as i32,
+                    );
+                    draw_commands(&mut s, &mut commands, &font);
+                }
+                None => draw_commands(&mut d, &mut commands, &font),
+            }
+            match commands.next() {
+                Some(RenderCommand::ScissorStart {
                     x,
                     y,
                     width,
                     height,
-                    color,
                     ..
-                } => {
-                    d.draw_rectangle(x as i32, y as i32, width as i32, height as i32, color);
+                }) => {
+                    // nested scissors only draw where all of their areas overlap
+                    let current = clips.last().copied().unwrap_or(BoundingBox {
+                        x: 0.0,
+                        y: 0.0,
+                        width: screen.0,
+                        height: screen.1,
+                    });
+                    let area = BoundingBox {
+                        x,
+                        y,
+                        width,
+                        height,
+                    };
+                    clips.push(area.intersection(&current));
                 }
-                RenderCommand::DrawText {
-                    text,
-                    x,
-                    y,
-                    color,
-                    font_size,
-                    ..
-                } => {
-                    d.draw_text(text.as_str(), x as i32, y as i32, font_size as i32, color);
+                Some(RenderCommand::ScissorEnd) => {
+                    clips.pop();
                 }
-                _ => {}
+                _ => break,
             }
         }
     }
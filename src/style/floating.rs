@@ -0,0 +1,102 @@
+use derive_setters::Setters;
+
+/// What a floating element is anchored to.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AttachTo {
+    /// Default. Anchors the element to the container it was declared in.
+    #[default]
+    Parent,
+    /// Anchors the element to the root of the layout (the whole window).
+    Root,
+}
+
+/// A point on the bounding box of an element, used to attach floating elements.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AttachPoint {
+    /// Default. The top left corner.
+    #[default]
+    TopLeft,
+    /// The center of the top side.
+    TopCenter,
+    /// The top right corner.
+    TopRight,
+    /// The center of the left side.
+    CenterLeft,
+    /// The center of the box.
+    Center,
+    /// The center of the right side.
+    CenterRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The center of the bottom side.
+    BottomCenter,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl AttachPoint {
+    /// Returns the position of the point as a fraction of the box size (horizontal, vertical).
+    pub fn factors(&self) -> (f64, f64) {
+        match self {
+            AttachPoint::TopLeft => (0.0, 0.0),
+            AttachPoint::TopCenter => (0.5, 0.0),
+            AttachPoint::TopRight => (1.0, 0.0),
+            AttachPoint::CenterLeft => (0.0, 0.5),
+            AttachPoint::Center => (0.5, 0.5),
+            AttachPoint::CenterRight => (1.0, 0.5),
+            AttachPoint::BottomLeft => (0.0, 1.0),
+            AttachPoint::BottomCenter => (0.5, 1.0),
+            AttachPoint::BottomRight => (1.0, 1.0),
+        }
+    }
+}
+
+/// Floating configuration for a flex container.
+///
+/// A floating element is taken out of the normal flow: it doesn't take up any
+/// space in its parent, and it is drawn after all the other elements.
+/// The `element_point` of the floating element is placed on the `parent_point`
+/// of what it is attached to, then moved by `offset`.
+///
+/// Example:
+/// ```ignore
+/// // a tooltip right below its parent
+/// FlexStyle::new().floating(
+///     Floating::new()
+///         .parent_point(AttachPoint::BottomLeft)
+///         .offset((0.0, 4.0))
+///         .z_index(10),
+/// )
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq, Setters)]
+pub struct Floating {
+    /// What the element is anchored to.
+    pub attach_to: AttachTo,
+    /// The point of the parent (or root) the element is anchored to.
+    pub parent_point: AttachPoint,
+    /// The point of the element that gets placed on the `parent_point`.
+    pub element_point: AttachPoint,
+    /// Additional offset (x, y) in pixels, applied after anchoring.
+    pub offset: (f64, f64),
+    /// Floating elements are drawn in ascending z-index order, after the normal flow.
+    pub z_index: i16,
+}
+
+impl Floating {
+    /// alias for `Floating::default()`
+    pub fn new() -> Self {
+        Floating::default()
+    }
+
+    /// Returns the position of a floating element of the given size, anchored to
+    /// the given parent bounding box (x, y, width, height).
+    pub fn position(&self, parent: (f64, f64, f64, f64), size: (f64, f64)) -> (f64, f64) {
+        let (x, y, width, height) = parent;
+        let (parent_x, parent_y) = self.parent_point.factors();
+        let (element_x, element_y) = self.element_point.factors();
+        (
+            x + parent_x * width - element_x * size.0 + self.offset.0,
+            y + parent_y * height - element_y * size.1 + self.offset.1,
+        )
+    }
+}
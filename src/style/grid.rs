@@ -0,0 +1,118 @@
+use alloc::vec::Vec;
+use derive_setters::Setters;
+
+use crate::style::{
+    KaolinColor, border::Border, layout::Alignment, padding::Padding, sizing::BoxSizing,
+    sizing::Sizing,
+};
+
+/// Style for a grid container
+///
+/// Columns and rows are defined as tracks, each with its own [`Sizing`]:
+/// - `fixed!(size)` tracks always have the same size
+/// - `fit!()` tracks are as big as their biggest child
+/// - `grow!(factor)` tracks share the space left by the other tracks, by factor
+/// - `percent!(fraction)` tracks take a fraction of the grid's inner size, without gaps
+///
+/// Children placed outside of the defined tracks get implicit fit tracks.
+#[derive(Clone, Setters)]
+#[setters(no_std)]
+pub struct GridStyle<Color>
+where
+    Color: Default + Copy + PartialEq + KaolinColor,
+{
+    /// Set the text color to be inherited by default by all child elements
+    #[setters(strip_option)]
+    pub color: Option<Color>,
+    /// The background color for the grid
+    #[setters(strip_option)]
+    pub background_color: Option<Color>,
+    /// The sizing configuration for the grid itself
+    pub sizing: BoxSizing,
+    /// The padding around the grid
+    pub padding: Padding,
+    /// The corner radius for the grid
+    pub corner_radius: f32,
+    /// The border configuration for the grid
+    pub border: Border<Color>,
+    /// The sizing of each column track, from left to right
+    #[setters(into)]
+    pub columns: Vec<Sizing>,
+    /// The sizing of each row track, from top to bottom
+    #[setters(into)]
+    pub rows: Vec<Sizing>,
+    /// The gap between columns.
+    pub column_gap: f64,
+    /// The gap between rows.
+    pub row_gap: f64,
+    /// The alignment of the children within their cells, on both axes, unless
+    /// they define their own. Stretched children fill their cells. (Default: Stretch)
+    pub alignment: Alignment,
+}
+
+impl<Color> Default for GridStyle<Color>
+where
+    Color: Default + Copy + PartialEq + KaolinColor,
+{
+    fn default() -> Self {
+        GridStyle {
+            color: None,
+            background_color: None,
+            sizing: BoxSizing::default(),
+            padding: Padding::default(),
+            corner_radius: 0.0,
+            border: Border::default(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            column_gap: 0.0,
+            row_gap: 0.0,
+            alignment: Alignment::Stretch,
+        }
+    }
+}
+
+impl<Color> GridStyle<Color>
+where
+    Color: Default + Copy + PartialEq + KaolinColor,
+{
+    /// alias for `GridStyle::default()`
+    pub fn new() -> Self {
+        GridStyle::default()
+    }
+}
+
+/// The cell a child of a grid is placed in, along with how many tracks it spans.
+///
+/// Cells of different children may overlap, in which case the children are
+/// drawn on top of each other, in the order they were added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Setters)]
+pub struct GridCell {
+    /// The index of the first row of the cell.
+    pub row: usize,
+    /// The index of the first column of the cell.
+    pub column: usize,
+    /// The number of rows the cell spans. (Default: 1)
+    pub row_span: usize,
+    /// The number of columns the cell spans. (Default: 1)
+    pub column_span: usize,
+}
+
+impl GridCell {
+    /// Creates a cell spanning a single track, at the given row and column.
+    pub fn new(row: usize, column: usize) -> Self {
+        GridCell {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+        }
+    }
+
+    /// Returns true if the two cells share at least one track in both directions.
+    pub fn overlaps(&self, other: &GridCell) -> bool {
+        self.row < other.row + other.row_span
+            && other.row < self.row + self.row_span
+            && self.column < other.column + other.column_span
+            && other.column < self.column + self.column_span
+    }
+}
@@ -19,6 +19,13 @@ impl Direction {
     pub fn is_horizontal(&self) -> bool {
         matches!(self, Direction::LeftToRight | Direction::RightToLeft)
     }
+
+    /// Returns true if the direction places children from the end of the main
+    /// axis (RightToLeft or BottomToTop).
+    #[inline]
+    pub fn is_reversed(&self) -> bool {
+        matches!(self, Direction::RightToLeft | Direction::BottomToTop)
+    }
 }
 
 // /// Shorthand for layout directions.
@@ -43,7 +50,7 @@ impl Direction {
 //     };
 // }
 
-#[derive(Default, Clone, Copy)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum Alignment {
     /// Aligns the elements at the start of the cross axis (top with horizontal layout, left with vertical layout).
     #[default]
@@ -71,6 +78,28 @@ pub enum Justification {
     SpaceAround,
 }
 
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Wrap {
+    /// Default. Keeps all the elements on a single line, shrinking them if they overflow.
+    #[default]
+    NoWrap,
+    /// Moves the elements that don't fit in the main axis to a new line.
+    Wrap,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum LineAlignment {
+    /// Packs the lines at the start of the cross axis.
+    #[default]
+    Start,
+    /// Packs the lines at the end of the cross axis.
+    End,
+    /// Packs the lines at the center of the cross axis.
+    Center,
+    /// Distributes the lines evenly, with the first line at the start and the last at the end.
+    SpaceBetween,
+}
+
 /// Layout properties for the flex container (a.k.a, where do we put its children?)
 #[derive(Default, Clone, Copy, Setters)]
 pub struct Layout {
@@ -82,6 +111,12 @@ pub struct Layout {
     pub justification: Justification,
     /// The gap between child elements.
     pub gap: f64,
+    /// Whether child elements can be broken into multiple lines.
+    pub wrap: Wrap,
+    /// The gap between lines of child elements, only used when wrapping.
+    pub cross_gap: f64,
+    /// The alignment of the lines along the cross axis, only used when wrapping.
+    pub line_alignment: LineAlignment,
 }
 
 impl Layout {
@@ -2,17 +2,25 @@
 //! Contains types and traits for styling UI elements.
 //!
 //! This module provides definitions for both Flex Boxes with [`FlexStyle`] and text configuration with [`TextStyle`].
+//! Grid containers are styled with [`grid::GridStyle`].
 
 pub mod border;
+pub mod floating;
+pub mod grid;
 pub mod layout;
+pub mod overflow;
 pub mod padding;
 pub mod sizing;
+pub mod text;
 
 use derive_setters::Setters;
 
-use crate::style::layout::Layout;
+use crate::style::floating::Floating;
+use crate::style::layout::{Alignment, Layout};
+use crate::style::overflow::BoxOverflow;
 use crate::style::padding::Padding;
 use crate::style::sizing::BoxSizing;
+use crate::style::text::{LineHeight, TextAlignment, TextOverflow, WordBreak};
 
 /// Trait for defining colors in the Kaolin UI framework.
 /// This color should be tied to the renderer.
@@ -41,14 +49,28 @@ where
     pub background_color: Option<Color>,
     /// The layout configuration for the children of the flex item
     pub layout: Layout,
+    /// Overrides the parent's cross axis alignment for this flex item only
+    #[setters(strip_option)]
+    pub align_self: Option<Alignment>,
     /// The sizing configuration for the flex item
     pub sizing: BoxSizing,
+    /// Ties the height of the flex item to its final width (width / height),
+    /// see [`FlexStyle::aspect_ratio`]
+    #[setters(skip)]
+    pub aspect_ratio: Option<f64>,
     /// The padding around the flex item
     pub padding: Padding,
+    /// The outer space around the flex item, inside of its parent
+    pub margin: Padding,
     /// The corner radius for the flex item
     pub corner_radius: f32,
     /// The border configuration for the flex item
     pub border: border::Border<Color>,
+    /// How children that don't fit in the flex item are handled, for each axis
+    pub overflow: BoxOverflow,
+    /// Takes the flex item out of the normal flow, anchoring it to its parent or to the root
+    #[setters(strip_option)]
+    pub floating: Option<Floating>,
 }
 
 impl<Color> Default for FlexStyle<Color>
@@ -60,10 +82,15 @@ where
             color: None,
             background_color: None,
             layout: Layout::default(),
+            align_self: None,
             sizing: BoxSizing::default(),
+            aspect_ratio: None,
             padding: Padding::default(),
+            margin: Padding::default(),
             corner_radius: 0.0,
             border: border::Border::default(),
+            overflow: BoxOverflow::default(),
+            floating: None,
         }
     }
 }
@@ -77,6 +104,13 @@ where
         FlexStyle::default()
     }
 
+    /// ### Ties the height of the flex item to its final width (width / height)
+    /// Ratios that aren't finite and positive are ignored, leaving the height free.
+    pub fn aspect_ratio(mut self, ratio: f64) -> Self {
+        self.aspect_ratio = (ratio.is_finite() && ratio > 0.0).then_some(ratio);
+        self
+    }
+
     #[inline]
     pub fn has_horizontal_layout(&self) -> bool {
         self.layout.direction.is_horizontal()
@@ -105,6 +139,27 @@ where
     /// Color of the text.
     #[setters(strip_option)]
     pub color: Option<Color>,
+    /// Overrides the parent's cross axis alignment for this text element only
+    #[setters(strip_option)]
+    pub align_self: Option<Alignment>,
+    /// The horizontal alignment of the lines within the text element.
+    pub text_align: TextAlignment,
+    /// The height of each line of text.
+    pub line_height: LineHeight,
+    /// Extra space between letters, in pixels. It is up to the `measure_text`
+    /// function and the renderer to apply it, see [`RenderCommand::DrawText`].
+    ///
+    /// [`RenderCommand::DrawText`]: crate::commands::RenderCommand::DrawText
+    pub letter_spacing: f64,
+    /// Extra space between paragraphs (lines separated by `\n`), in pixels.
+    pub paragraph_spacing: f64,
+    /// The maximum number of lines to show, the others being cut according to `overflow`.
+    #[setters(strip_option)]
+    pub max_lines: Option<usize>,
+    /// How lines that don't fit are shortened.
+    pub overflow: TextOverflow,
+    /// Where lines may break, allowing long words (URLs, hashes...) to wrap.
+    pub word_break: WordBreak,
 }
 
 impl<Color> Default for TextStyle<Color>
@@ -116,6 +171,14 @@ where
             font_id: 0,
             font_size: 16.0,
             color: None,
+            align_self: None,
+            text_align: TextAlignment::default(),
+            line_height: LineHeight::default(),
+            letter_spacing: 0.0,
+            paragraph_spacing: 0.0,
+            max_lines: None,
+            overflow: TextOverflow::default(),
+            word_break: WordBreak::default(),
         }
     }
 }
@@ -0,0 +1,69 @@
+use derive_setters::Setters;
+
+/// How the content of a flex container that doesn't fit is handled, on one axis.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum Overflow {
+    /// Default. The content is drawn outside of the container, and children
+    /// shrink to fit it if they can.
+    #[default]
+    Visible,
+    /// The content is cut at the edges of the container, and children keep
+    /// their size instead of shrinking.
+    Clip,
+    /// Like [`Overflow::Clip`], with the content shifted back by the given offset
+    /// in pixels. The offset is not limited to the size of the content.
+    Scroll(f64),
+}
+
+impl Overflow {
+    /// True if the content is cut at the edges of the container.
+    #[inline]
+    pub fn clips(&self) -> bool {
+        !matches!(self, Overflow::Visible)
+    }
+
+    /// Returns the distance the content is shifted by.
+    #[inline]
+    pub fn scroll_offset(&self) -> f64 {
+        match self {
+            Overflow::Scroll(offset) => *offset,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Overflow configuration of a flex container, for each axis.
+///
+/// Example:
+/// ```ignore
+/// // a log viewer, scrolled 120px down
+/// FlexStyle::new().overflow(BoxOverflow::new().y(Overflow::Scroll(120.0)))
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Debug, Setters)]
+pub struct BoxOverflow {
+    /// Overflow behavior on the horizontal axis.
+    pub x: Overflow,
+    /// Overflow behavior on the vertical axis.
+    pub y: Overflow,
+}
+
+impl BoxOverflow {
+    /// alias for `BoxOverflow::default()`
+    pub fn new() -> Self {
+        BoxOverflow::default()
+    }
+
+    /// Creates an overflow configuration with the same behavior on both axes.
+    pub fn all(overflow: Overflow) -> Self {
+        BoxOverflow {
+            x: overflow,
+            y: overflow,
+        }
+    }
+
+    /// True if the content is cut on at least one axis.
+    #[inline]
+    pub fn clips(&self) -> bool {
+        self.x.clips() || self.y.clips()
+    }
+}
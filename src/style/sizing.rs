@@ -9,6 +9,8 @@ pub enum PreferredSize {
     Fixed(PositiveFinite),
     // no fixed size, grows indefenetly with a factor
     Grow(PositiveFinite),
+    // a fraction of the parent's inner size, resolved during the growing phase
+    Percent(PositiveFinite),
 }
 
 impl Default for PreferredSize {
@@ -49,7 +51,31 @@ impl SizingDimensions {
     /// True if the sizing is shrinkable and can be reduced in size when
     /// overflowing its container.
     pub fn is_shrinkable(&self) -> bool {
-        self.max > self.min // Allow shrinking if not fixed
+        // Allow shrinking if not fixed, percentages are resolved instead
+        self.max > self.min && !self.is_percent()
+    }
+
+    /// True if the sizing is a fraction of the parent's size.
+    pub fn is_percent(&self) -> bool {
+        matches!(self.preferred, PreferredSize::Percent(_))
+    }
+
+    /// Returns the size of the dimension relative to the given parent size,
+    /// if the sizing is a percentage.
+    ///
+    /// ```
+    /// # use kaolin::{percent, fixed, style::sizing::SizingDimensions};
+    /// let sized = SizingDimensions::from(percent!(0.3));
+    /// assert_eq!(sized.get_percent_of(200.0), Some(60.0));
+    /// assert_eq!(SizingDimensions::from(fixed!(10.0)).get_percent_of(200.0), None);
+    /// ```
+    pub fn get_percent_of(&self, parent_size: f64) -> Option<f64> {
+        match self.preferred {
+            PreferredSize::Percent(fraction) => {
+                Some(self.clamped(f64::from(fraction) * parent_size))
+            }
+            _ => None,
+        }
     }
 
     /// Returns the growth factor for the dimension.
@@ -187,8 +213,47 @@ pub enum Sizing {
         min: Option<PositiveFinite>,
         max: Option<Positive>,
     },
+    /// A fraction of the parent's inner size, between `0.0` and `1.0`.
+    Percent(PositiveFinite),
 }
 
+impl Sizing {
+    /// ### Creates a percentage sizing, checking the fraction
+    /// The fraction must be between `0.0` and `1.0`, see [`percent!`](crate::percent).
+    ///
+    /// ```
+    /// # use kaolin::style::sizing::{InvalidFraction, Sizing};
+    /// assert!(Sizing::percent(0.5).is_ok());
+    /// assert_eq!(Sizing::percent(1.5).err(), Some(InvalidFraction(1.5)));
+    /// assert!(Sizing::percent(f64::NAN).is_err());
+    /// ```
+    pub fn percent(fraction: f64) -> Result<Self, InvalidFraction> {
+        if (0.0..=1.0).contains(&fraction) {
+            // the range excludes NaN and infinities
+            Ok(Sizing::Percent(PositiveFinite::new(fraction).unwrap()))
+        } else {
+            Err(InvalidFraction(fraction))
+        }
+    }
+}
+
+/// The error returned by [`Sizing::percent`] for a fraction which is NaN or
+/// outside of `0.0..=1.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InvalidFraction(pub f64);
+
+impl core::fmt::Display for InvalidFraction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "invalid fraction {}, expected a value between 0 and 1",
+            self.0
+        )
+    }
+}
+
+impl core::error::Error for InvalidFraction {}
+
 impl From<Sizing> for SizingDimensions {
     fn from(sizing: Sizing) -> Self {
         match sizing {
@@ -208,6 +273,10 @@ impl From<Sizing> for SizingDimensions {
                 preferred: PreferredSize::Grow(factor.unwrap_or(PositiveFinite::new(1.0).unwrap())),
                 max: max.unwrap_or(Positive::new(f64::INFINITY).unwrap()),
             },
+            Sizing::Percent(fraction) => SizingDimensions {
+                preferred: PreferredSize::Percent(fraction),
+                ..SizingDimensions::default()
+            },
         }
     }
 }
@@ -286,3 +355,24 @@ macro_rules! grow {
         }
     };
 }
+
+/// Defines a percentage sizing behavior.
+///
+/// - `percent!(fraction)` will size the element to a fraction (`0.0` to `1.0`)
+///   of its parent's inner size, that is without the parent's padding and gaps.
+///
+/// Panics on invalid fractions (NaN or outside of `0.0..=1.0`), use
+/// [`Sizing::percent`] to handle them yourself.
+///
+/// Example:
+/// ```ignore
+/// // a 30/70 split, regardless of the content
+/// k.with(FlexStyle::new().sizing(sizing!(percent!(0.3), grow!())), |k| k)
+///     .with(FlexStyle::new().sizing(sizing!(percent!(0.7), grow!())), |k| k)
+/// ```
+#[macro_export]
+macro_rules! percent {
+    ($fraction:expr) => {
+        $crate::style::sizing::Sizing::percent($fraction).unwrap()
+    };
+}
@@ -0,0 +1,83 @@
+//! Text specific style options, used by [`TextStyle`](super::TextStyle).
+
+/// Horizontal alignment of the lines of a text element, within its final width.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TextAlignment {
+    /// Default. Lines start at the left edge of the element.
+    #[default]
+    Left,
+    /// Lines are centered within the element.
+    Center,
+    /// Lines end at the right edge of the element.
+    Right,
+    /// Lines span the whole width of the element, spreading the space between
+    /// words. The last line of each paragraph is left aligned.
+    Justify,
+}
+
+/// The vertical distance between the tops of consecutive lines of text.
+///
+/// Text is vertically centered within its line when the line is taller than
+/// the measured height of the text.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum LineHeight {
+    /// Default. The height measured for each line by the `measure_text` function.
+    #[default]
+    Auto,
+    /// A fixed height in pixels.
+    Absolute(f64),
+    /// A multiplier of the font size, e.g. `Relative(1.4)`.
+    Relative(f64),
+}
+
+impl LineHeight {
+    /// Returns the height of a line, given its measured height and the font size.
+    #[inline]
+    pub fn resolve(&self, measured: f64, font_size: f32) -> f64 {
+        match self {
+            LineHeight::Auto => measured,
+            LineHeight::Absolute(height) => *height,
+            LineHeight::Relative(multiplier) => multiplier * font_size as f64,
+        }
+    }
+}
+
+/// How a text element shortens lines that don't fit, and the last visible
+/// line when `max_lines` cuts the text.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TextOverflow {
+    /// Default. Lines after `max_lines` are dropped, and lines wider than
+    /// the element are drawn past its edge.
+    #[default]
+    Clip,
+    /// The end of the line is replaced with "…".
+    EndEllipsis,
+    /// The middle of the line is replaced with "…", keeping both ends,
+    /// e.g. for file paths.
+    MiddleEllipsis,
+    /// The start of the line is replaced with "…".
+    StartEllipsis,
+}
+
+impl TextOverflow {
+    /// True if overflowing lines are shortened with an ellipsis.
+    #[inline]
+    pub fn ellipsizes(&self) -> bool {
+        !matches!(self, TextOverflow::Clip)
+    }
+}
+
+/// Where lines of text may break, besides between words.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum WordBreak {
+    /// Default. Lines only break at Unicode line break opportunities (between
+    /// words, after hyphens, between CJK ideographs...), and words wider than
+    /// the element overflow it.
+    #[default]
+    Normal,
+    /// Lines may break between any two graphemes, filling each line.
+    BreakAll,
+    /// Lines break between words, and words wider than the element are split
+    /// between graphemes, like CSS `overflow-wrap: anywhere`.
+    BreakWord,
+}
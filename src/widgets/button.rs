@@ -0,0 +1,37 @@
+//! ## Button
+//! A clickable container with a label.
+
+use crate::{
+    elements::{text::TextElement, traits::KaolinElement},
+    kaolin::scope::KaolinScope,
+    style::KaolinColor,
+    widgets::WidgetStyle,
+};
+
+#[allow(private_bounds)]
+impl<'frame, Color, CustomData: 'frame> KaolinScope<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + KaolinColor + 'static,
+    TextElement<Color>: KaolinElement<'frame, Color, CustomData>,
+{
+    /// ### Add a button with a label
+    ///
+    /// Returns the scope along with whether the button was clicked, see
+    /// [`KaolinScope::is_clicked`].
+    ///
+    /// Example:
+    /// ```ignore
+    /// let (k, save) = k.button("save", "Save", &WidgetStyle::new());
+    /// if save {
+    ///     document.save();
+    /// }
+    /// ```
+    pub fn button(self, id: &str, label: &str, style: &WidgetStyle<Color>) -> (Self, bool) {
+        let clicked = self.is_clicked(id);
+        let container = style.container_style(self.is_hovered(id));
+        let k = self
+            .focusable(id)
+            .with(container, |k| k.text(label, style.text));
+        (k, clicked)
+    }
+}
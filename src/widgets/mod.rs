@@ -0,0 +1,132 @@
+//! ## Widgets
+//! Standard controls (buttons, checkboxes, radio groups, sliders, progress bars
+//! and separators) built from containers and text, as methods on [`KaolinScope`].
+//!
+//! Interactive widgets take their arguments in the same order: an id first,
+//! then the label or options, the value they edit in place, and the style last.
+//! They are focusable, and buttons return whether they were clicked. Like with
+//! [`KaolinScope::is_clicked`], interactions are based on the previous frame.
+
+pub mod button;
+pub mod range;
+pub mod toggle;
+
+use derive_setters::Setters;
+
+use crate::{
+    elements::{text::TextElement, traits::KaolinElement},
+    fixed, grow,
+    kaolin::scope::KaolinScope,
+    sizing,
+    style::{
+        FlexStyle, KaolinColor, TextStyle,
+        border::Border,
+        layout::{Alignment, Layout},
+        padding::Padding,
+    },
+};
+
+/// Style shared by all the widgets.
+///
+/// Example:
+/// ```ignore
+/// let style = WidgetStyle::new()
+///     .accent(Color::BLUE)
+///     .hover_background(Color::LIGHTGRAY);
+/// let (k, save) = k.button("save", "Save", &style);
+/// ```
+#[derive(Clone, Copy, Setters)]
+pub struct WidgetStyle<Color>
+where
+    Color: Default + Copy + PartialEq + KaolinColor,
+{
+    /// The style of the outer container of the widget.
+    pub container: FlexStyle<Color>,
+    /// The style of the label of the widget.
+    pub text: TextStyle<Color>,
+    /// The color of indicators, filled parts and separators.
+    pub accent: Color,
+    /// The background color of the container while hovered.
+    #[setters(strip_option)]
+    pub hover_background: Option<Color>,
+    /// The size of check boxes and radio buttons, and the thickness of sliders and progress bars.
+    pub indicator_size: f64,
+    /// The width of the indicator outlines and separators.
+    pub line_width: f64,
+}
+
+impl<Color> Default for WidgetStyle<Color>
+where
+    Color: Default + Copy + PartialEq + KaolinColor,
+{
+    fn default() -> Self {
+        WidgetStyle {
+            container: FlexStyle::new()
+                .padding(Padding::hor_ver(8.0, 4.0))
+                .layout(Layout::new().gap(8.0).alignment(Alignment::Center)),
+            text: TextStyle::new(),
+            accent: Color::default_foreground_color(),
+            hover_background: None,
+            indicator_size: 16.0,
+            line_width: 1.0,
+        }
+    }
+}
+
+impl<Color> WidgetStyle<Color>
+where
+    Color: Default + Copy + PartialEq + KaolinColor,
+{
+    /// alias for `WidgetStyle::default()`
+    pub fn new() -> Self {
+        WidgetStyle::default()
+    }
+
+    /// Returns the container style, with the hover background if hovered.
+    fn container_style(&self, hovered: bool) -> FlexStyle<Color> {
+        match (hovered, self.hover_background) {
+            (true, Some(color)) => self.container.background_color(color),
+            _ => self.container,
+        }
+    }
+
+    /// Returns the outline of indicators and tracks.
+    fn outline(&self) -> Border<Color> {
+        Border::new()
+            .width(self.line_width as f32)
+            .color(self.accent)
+    }
+
+    /// Returns the filled part of indicators and tracks.
+    fn fill(&self, corner_radius: f32) -> FlexStyle<Color> {
+        FlexStyle::new()
+            .sizing(sizing!(grow!()))
+            .background_color(self.accent)
+            .corner_radius(corner_radius)
+    }
+}
+
+#[allow(private_bounds)]
+impl<'frame, Color, CustomData: 'frame> KaolinScope<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + KaolinColor + 'static,
+    TextElement<Color>: KaolinElement<'frame, Color, CustomData>,
+{
+    /// ### Add a separator line
+    ///
+    /// The line spans the cross axis of the current container: it is horizontal
+    /// within a vertical layout, and vertical within a horizontal one.
+    pub fn separator(self, style: &WidgetStyle<Color>) -> Self {
+        let sizing = if self.has_horizontal_layout() {
+            sizing!(fixed!(style.line_width), grow!())
+        } else {
+            sizing!(grow!(), fixed!(style.line_width))
+        };
+        self.with(
+            FlexStyle::new()
+                .sizing(sizing)
+                .background_color(style.accent),
+            |k| k,
+        )
+    }
+}
@@ -0,0 +1,104 @@
+//! ## Ranges
+//! Sliders and progress bars: a track, filled up to a value.
+
+use core::ops::RangeInclusive;
+
+use crate::{
+    elements::{text::TextElement, traits::KaolinElement},
+    fixed, grow,
+    kaolin::{focus::FocusDirection, scope::KaolinScope},
+    percent, sizing,
+    style::{FlexStyle, KaolinColor},
+    widgets::WidgetStyle,
+};
+
+/// The number of keyboard steps across the range of a slider.
+const SLIDER_STEPS: f64 = 20.0;
+
+#[allow(private_bounds)]
+impl<'frame, Color, CustomData: 'frame> KaolinScope<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + KaolinColor + 'static,
+    TextElement<Color>: KaolinElement<'frame, Color, CustomData>,
+{
+    /// ### Add a horizontal slider
+    ///
+    /// `value` follows the pointer while the slider is dragged, even outside
+    /// of it, and is kept within the given range. While the slider is focused,
+    /// [`Kaolin::focus_towards`] left and right step it by a twentieth of the range.
+    /// The slider grows to fill the width of its container.
+    ///
+    /// Example:
+    /// ```
+    /// # use kaolin::{Kaolin, style::{KaolinColor, TextStyle}, widgets::WidgetStyle};
+    /// # #[derive(Default, Clone, Copy, PartialEq)]
+    /// # struct Color;
+    /// # impl KaolinColor for Color {}
+    /// # let kaolin = Kaolin::new((800, 600), |text: &str, _: &TextStyle<Color>| {
+    /// #     (text.len() as f64 * 10.0, 20.0)
+    /// # });
+    /// # let style = WidgetStyle::<Color>::new();
+    /// kaolin.draw::<()>(|k| {
+    ///     let volume = k.state::<f64>("volume");
+    ///     let mut value = volume.get();
+    ///     let k = k.slider("volume", 0.0..=100.0, &mut value, &style);
+    ///     volume.set(value); // keeps the dragged value for the next frame
+    ///     k
+    /// });
+    /// ```
+    ///
+    /// [`Kaolin::focus_towards`]: crate::kaolin::Kaolin::focus_towards
+    pub fn slider(
+        self,
+        id: &str,
+        range: RangeInclusive<f64>,
+        value: &mut f64,
+        style: &WidgetStyle<Color>,
+    ) -> Self {
+        let (min, max) = (*range.start(), *range.end());
+        if self.is_dragged(id)
+            && let Some((x, _)) = self.relative_pointer(id)
+        {
+            // the right edge itself is outside of the slider, snap to the max past it
+            *value = if x >= 1.0 {
+                max
+            } else {
+                min + x.max(0.0) * (max - min)
+            };
+        }
+        match self.adjustment(id) {
+            Some(FocusDirection::Left) => *value -= (max - min) / SLIDER_STEPS,
+            Some(FocusDirection::Right) => *value += (max - min) / SLIDER_STEPS,
+            _ => {}
+        }
+        let fraction = if max > min {
+            *value = value.clamp(min, max);
+            (*value - min) / (max - min)
+        } else {
+            0.0
+        };
+        self.adjustable(id).track(fraction, style)
+    }
+
+    /// ### Add a horizontal progress bar
+    ///
+    /// `progress` goes from 0 (empty) to 1 (full), and is clamped in between.
+    /// The bar grows to fill the width of its container.
+    pub fn progress_bar(self, progress: f64, style: &WidgetStyle<Color>) -> Self {
+        self.track(progress, style)
+    }
+
+    /// Adds a track filled up to the given fraction, empty if NaN.
+    fn track(self, fraction: f64, style: &WidgetStyle<Color>) -> Self {
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        let track = FlexStyle::new()
+            .sizing(sizing!(grow!(), fixed!(style.indicator_size)))
+            .border(style.outline());
+        let fill = style.fill(0.0).sizing(sizing!(percent!(fraction), grow!()));
+        self.with(track, |k| k.with(fill, |k| k))
+    }
+}
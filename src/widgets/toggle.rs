@@ -0,0 +1,121 @@
+//! ## Toggles
+//! Checkboxes and radio groups: labelled indicators toggled by clicking anywhere on them.
+
+use alloc::{format, vec::Vec};
+
+use crate::{
+    elements::{text::TextElement, traits::KaolinElement},
+    fixed,
+    kaolin::scope::KaolinScope,
+    sizing,
+    style::{FlexStyle, KaolinColor, padding::Padding},
+    widgets::WidgetStyle,
+};
+
+#[allow(private_bounds)]
+impl<'frame, Color, CustomData: 'frame> KaolinScope<'frame, Color, CustomData>
+where
+    Color: Default + Copy + PartialEq + KaolinColor + 'static,
+    TextElement<Color>: KaolinElement<'frame, Color, CustomData>,
+{
+    /// ### Add a checkbox with a label
+    ///
+    /// `checked` is toggled when the checkbox is clicked.
+    ///
+    /// Example:
+    /// ```
+    /// # use kaolin::{Kaolin, style::{KaolinColor, TextStyle}, widgets::WidgetStyle};
+    /// # #[derive(Default, Clone, Copy, PartialEq)]
+    /// # struct Color;
+    /// # impl KaolinColor for Color {}
+    /// # let kaolin = Kaolin::new((800, 600), |text: &str, _: &TextStyle<Color>| {
+    /// #     (text.len() as f64 * 10.0, 20.0)
+    /// # });
+    /// # let style = WidgetStyle::<Color>::new();
+    /// kaolin.draw::<()>(|k| {
+    ///     let dark_mode = k.state::<bool>("dark-mode");
+    ///     let mut checked = dark_mode.get();
+    ///     let k = k.checkbox("dark-mode", "Dark mode", &mut checked, &style);
+    ///     dark_mode.set(checked); // keeps the toggled value for the next frame
+    ///     k
+    /// });
+    /// ```
+    pub fn checkbox(
+        self,
+        id: &str,
+        label: &str,
+        checked: &mut bool,
+        style: &WidgetStyle<Color>,
+    ) -> Self {
+        if self.is_clicked(id) {
+            *checked = !*checked;
+        }
+        self.toggle(id, label, *checked, 0.0, style)
+    }
+
+    /// ### Add a group of radio buttons, one for each option
+    ///
+    /// The buttons are added to the current container, with the ids `{id}/0`,
+    /// `{id}/1`, and so on. `selected` is set to the value of the clicked option.
+    ///
+    /// Example:
+    /// ```ignore
+    /// let mut size = Size::Medium;
+    /// k.with(FlexStyle::new().layout(Layout::new().direction(Direction::TopToBottom)), |k| {
+    ///     k.radio_group("size", &[(Size::Small, "Small"), (Size::Medium, "Medium")], &mut size, &style)
+    /// })
+    /// ```
+    pub fn radio_group<T: PartialEq + Clone>(
+        self,
+        id: &str,
+        options: &[(T, &str)],
+        selected: &mut T,
+        style: &WidgetStyle<Color>,
+    ) -> Self {
+        let ids = (0..options.len())
+            .map(|index| format!("{id}/{index}"))
+            .collect::<Vec<_>>();
+        if let Some((value, _)) = options
+            .iter()
+            .zip(&ids)
+            .find(|(_, id)| self.is_clicked(id))
+            .map(|(option, _)| option)
+        {
+            *selected = value.clone();
+        }
+        let radius = (style.indicator_size / 2.0) as f32;
+        options
+            .iter()
+            .zip(&ids)
+            .fold(self, |k, ((value, label), id)| {
+                k.toggle(id, label, value == selected, radius, style)
+            })
+    }
+
+    /// Adds a labelled indicator, filled when active.
+    fn toggle(
+        self,
+        id: &str,
+        label: &str,
+        active: bool,
+        corner_radius: f32,
+        style: &WidgetStyle<Color>,
+    ) -> Self {
+        let container = style.container_style(self.is_hovered(id));
+        let indicator = FlexStyle::new()
+            .sizing(sizing!(fixed!(style.indicator_size)))
+            .padding(Padding::all(style.line_width * 2.0))
+            .border(style.outline())
+            .corner_radius(corner_radius);
+        self.focusable(id).with(container, |k| {
+            k.with(indicator, |k| {
+                if active {
+                    k.with(style.fill(corner_radius), |k| k)
+                } else {
+                    k
+                }
+            })
+            .text(label, style.text)
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use kaolin::{
-    Kaolin, fixed, sizing,
+    Kaolin, fit, fixed, grow, sizing,
     style::{
         FlexStyle, TextStyle,
         layout::{Alignment, Direction, Justification, Layout},
@@ -150,7 +150,6 @@ fn horizontal_space_around_justification() {
 /// Tests different directions with complex alignment combinations.
 /// Verifies that right-to-left and bottom-to-top directions work correctly.
 #[test]
-#[ignore = "reason: implement RightToLeft!"]
 fn different_directions_complex_alignment() {
     let kaolin = Kaolin::new((400, 300), measure_text);
     let mut commands = kaolin.draw::<()>(|k| {
@@ -176,10 +175,99 @@ fn different_directions_complex_alignment() {
     assert_position!(commands.next(), (290.0, 280.0)); // Second text
 }
 
+/// Tests bottom-to-top direction with start justification.
+/// Verifies that the first child is placed at the bottom edge and the following ones above it.
+#[test]
+fn bottom_to_top_start_justification() {
+    let kaolin = Kaolin::new((400, 300), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(400.0), fixed!(300.0)))
+                .padding(Padding::all(10.0))
+                .layout(Layout::new().direction(Direction::BottomToTop).gap(5.0)),
+            |k| {
+                k.text("Newest", TextStyle::new())
+                    .text("Oldest", TextStyle::new())
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (400.0, 300.0));
+    // content box ends at 290, each line is 20px tall
+    assert_position!(commands.next(), (10.0, 270.0)); // Newest, at the bottom
+    assert_position!(commands.next(), (10.0, 245.0)); // Oldest, above with the gap
+}
+
+/// Tests that justification is mirrored in right-to-left layouts.
+/// End justification packs the children to the left edge, with the first child rightmost.
+#[test]
+fn right_to_left_end_justification() {
+    let kaolin = Kaolin::new((400, 300), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(400.0), fixed!(300.0)))
+                .layout(
+                    Layout::new()
+                        .direction(Direction::RightToLeft)
+                        .justification(Justification::End),
+                ),
+            |k| {
+                k.text("First", TextStyle::new()) // 50px wide
+                    .text("Second", TextStyle::new()) // 60px wide
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (400.0, 300.0));
+    assert_position!(commands.next(), (60.0, 0.0)); // First text, right of Second
+    assert_position!(commands.next(), (0.0, 0.0)); // Second text, at the left edge
+}
+
+/// Tests space-between justification in a right-to-left layout with asymmetric padding.
+/// Children should be placed like in a left-to-right layout, mirrored within the padded box.
+#[test]
+fn right_to_left_space_between_with_padding() {
+    let kaolin = Kaolin::new((400, 300), measure_text);
+    let positions = |direction| {
+        kaolin
+            .draw::<()>(|k| {
+                k.with(
+                    FlexStyle::new()
+                        .sizing(sizing!(fixed!(400.0), fixed!(300.0)))
+                        .padding(Padding::new(20.0, 10.0, 0.0, 0.0))
+                        .layout(
+                            Layout::new()
+                                .direction(direction)
+                                .justification(Justification::SpaceBetween),
+                        ),
+                    |k| {
+                        k.text("A", TextStyle::new()) // 10px wide
+                            .text("B", TextStyle::new()) // 10px wide
+                            .text("C", TextStyle::new()) // 10px wide
+                    },
+                )
+            })
+            .filter_map(|command| match command {
+                kaolin::commands::RenderCommand::DrawText { x, .. } => Some(x),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let left_to_right = positions(Direction::LeftToRight);
+    let right_to_left = positions(Direction::RightToLeft);
+    assert_eq!(right_to_left[0], 380.0); // A touches the right content edge
+    // the content box spans 20..390, each child being 10px wide
+    for (ltr, rtl) in left_to_right.into_iter().zip(right_to_left) {
+        assert_eq!(rtl, 20.0 + 390.0 - ltr - 10.0);
+    }
+}
+
 /// Tests stretch alignment in a vertical layout.
 /// Ensures that child elements expand to fill the cross-axis (width in vertical layout).
 #[test]
-#[ignore = "reason: implement stretch?"]
 fn vertical_stretch_alignment() {
     let kaolin = Kaolin::new((800, 600), measure_text);
     let mut commands = kaolin.draw::<()>(|k| {
@@ -200,6 +288,102 @@ fn vertical_stretch_alignment() {
     assert_position!(commands.next(), (0.0, 0.0)); // Child position
 }
 
+/// Tests stretch alignment in a horizontal layout.
+/// Children fill the container height but stay within their own maximum size.
+#[test]
+fn horizontal_stretch_alignment_respects_max() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(400.0), fixed!(100.0)))
+                .padding(Padding::vertical(10.0))
+                .layout(Layout::new().alignment(Alignment::Stretch)),
+            |k| {
+                k.with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fit!())),
+                    |k| k,
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fit!(60.0))),
+                    |k| k,
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fixed!(30.0))),
+                    |k| k,
+                )
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (400.0, 100.0));
+    assert_size!(commands.next(), (50.0, 80.0)); // stretched to the inner height
+    assert_size!(commands.next(), (50.0, 60.0)); // clamped by its max height
+    assert_size!(commands.next(), (50.0, 30.0)); // fixed sizes are left alone
+}
+
+/// Tests that stretched containers propagate their new size to their children.
+#[test]
+fn stretch_propagates_to_descendants() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(200.0), fixed!(600.0)))
+                .layout(
+                    Layout::new()
+                        .direction(Direction::TopToBottom)
+                        .alignment(Alignment::Stretch),
+                ),
+            |k| {
+                k.with(FlexStyle::new(), |k| {
+                    k.with(
+                        FlexStyle::new().sizing(sizing!(grow!(), fixed!(10.0))),
+                        |k| k,
+                    )
+                })
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (200.0, 600.0));
+    assert_size!(commands.next(), (200.0, 10.0)); // stretched item
+    assert_size!(commands.next(), (200.0, 10.0)); // its growing child follows
+}
+
+/// Tests per-child alignment overrides in a centered toolbar.
+/// Only the overriding children move, the others keep the parent's alignment.
+#[test]
+fn align_self_overrides_parent_alignment() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(400.0), fixed!(100.0)))
+                .layout(Layout::new().alignment(Alignment::Center)),
+            |k| {
+                k.with(FlexStyle::new().sizing(sizing!(fixed!(20.0))), |k| k)
+                    .with(
+                        FlexStyle::new()
+                            .sizing(sizing!(fixed!(20.0), fit!()))
+                            .align_self(Alignment::Stretch),
+                        |k| k,
+                    )
+                    .text("Status", TextStyle::new().align_self(Alignment::End))
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (400.0, 100.0));
+    assert_position!(commands.next(), (0.0, 40.0)); // centered, like the parent says
+    assert_multiple!(
+        commands.next(),
+        assert_size((20.0, 100.0)), // stretched on its own
+        assert_position((20.0, 0.0))
+    );
+    assert_position!(commands.next(), (40.0, 80.0)); // bottom aligned text
+}
+
 /// Tests alignment and justification with padding.
 /// Verifies that padding is correctly accounted for in positioning calculations.
 #[test]
@@ -18,11 +18,13 @@ fn render_text_element() {
     assert_eq!(
         commands.collect::<Vec<_>>(),
         vec![RenderCommand::DrawText {
+            id: "".to_string(),
             text: "Hello, Kaolin!".to_string(),
             x: 0.0,
             y: 0.0,
             font_id: 0,
             font_size: 16.0,
+            letter_spacing: 0.0,
             color: TestColor::Black,
         },]
     );
@@ -66,11 +68,13 @@ fn render_text_with_custom_font() {
     assert_eq!(
         commands.collect::<Vec<_>>(),
         vec![RenderCommand::DrawText {
+            id: "".to_string(),
             text: "Custom Font".to_string(),
             x: 0.0,
             y: 0.0,
             font_id: 5,
             font_size: 24.0,
+            letter_spacing: 0.0,
             color: TestColor::Black,
         },]
     );
@@ -102,11 +106,13 @@ fn render_container_with_text() {
                 border: Border::default(),
             },
             RenderCommand::DrawText {
+                id: "".to_string(),
                 text: "Inside".to_string(),
                 x: 0.0,
                 y: 0.0,
                 font_id: 0,
                 font_size: 16.0,
+                letter_spacing: 0.0,
                 color: TestColor::Black,
             },
         ]
@@ -128,27 +134,33 @@ fn render_multiple_text_elements() {
         commands.collect::<Vec<_>>(),
         vec![
             RenderCommand::DrawText {
+                id: "".to_string(),
                 text: "First".to_string(),
                 x: 0.0,
                 y: 0.0,
                 font_id: 0,
                 font_size: 16.0,
+                letter_spacing: 0.0,
                 color: TestColor::Black,
             },
             RenderCommand::DrawText {
+                id: "".to_string(),
                 text: "Second".to_string(),
                 x: 50.0, // First text width (5 chars * 10px)
                 y: 0.0,
                 font_id: 0,
                 font_size: 16.0,
+                letter_spacing: 0.0,
                 color: TestColor::Black,
             },
             RenderCommand::DrawText {
+                id: "".to_string(),
                 text: "Third".to_string(),
                 x: 110.0, // First + Second width (5 + 6 chars * 10px)
                 y: 0.0,
                 font_id: 0,
                 font_size: 16.0,
+                letter_spacing: 0.0,
                 color: TestColor::Black,
             },
         ]
@@ -180,11 +192,13 @@ fn render_fit_to_content_container() {
                 border: Border::default(),
             },
             RenderCommand::DrawText {
+                id: "".to_string(),
                 text: "Auto Size".to_string(),
                 x: 0.0,
                 y: 0.0,
                 font_id: 0,
                 font_size: 16.0,
+                letter_spacing: 0.0,
                 color: TestColor::Black,
             },
         ]
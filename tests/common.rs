@@ -1,4 +1,9 @@
-use kaolin::style::{KaolinColor, TextStyle};
+use kaolin::{
+    Kaolin,
+    commands::{RenderCommand, RenderCommands},
+    fit, fixed, sizing,
+    style::{FlexStyle, KaolinColor, TextStyle},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum TestColor {
@@ -24,6 +29,33 @@ pub fn measure_text(text: &str, _config: &TextStyle<TestColor>) -> (f64, f64) {
     (text.len() as f64 * 10.0, 20.0)
 }
 
+/// Draws text in a container of the given width, fitting its height.
+#[allow(dead_code)]
+pub fn draw_text_in(
+    kaolin: &Kaolin<TestColor>,
+    width: f64,
+    content: &str,
+    style: TextStyle<TestColor>,
+) -> RenderCommands<TestColor, ()> {
+    kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new().sizing(sizing!(fixed!(width), fit!())),
+            |k| k.text(content, style),
+        )
+    })
+}
+
+/// Collects the drawn lines of text.
+#[allow(dead_code)]
+pub fn lines(commands: RenderCommands<TestColor, ()>) -> Vec<String> {
+    commands
+        .filter_map(|command| match command {
+            RenderCommand::DrawText { text, .. } => Some(text),
+            _ => None,
+        })
+        .collect()
+}
+
 #[macro_export]
 macro_rules! assert_render_commands {
     ($output:expr, $expected:expr $(,)?) => {{
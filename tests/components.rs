@@ -0,0 +1,74 @@
+use kaolin::{
+    Kaolin, fixed,
+    kaolin::{component::Component, scope::KaolinScope},
+    sizing,
+    style::{FlexStyle, KaolinColor, TextStyle, padding::Padding},
+};
+
+mod common;
+use common::*;
+
+/// A card with a title, generic over the color type like a shared component would be.
+struct Card<'a> {
+    id: &'a str,
+    title: &'a str,
+}
+
+impl<Color: KaolinColor + 'static, CustomData> Component<Color, CustomData> for Card<'_> {
+    fn render<'frame>(
+        &self,
+        k: KaolinScope<'frame, Color, CustomData>,
+    ) -> KaolinScope<'frame, Color, CustomData>
+    where
+        CustomData: 'frame,
+    {
+        k.text(self.title, TextStyle::new())
+    }
+
+    fn style(&self) -> FlexStyle<Color> {
+        FlexStyle::new().padding(Padding::all(10.0))
+    }
+
+    fn id(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+/// Tests that a component is rendered in a container with its own style and id.
+#[test]
+fn component_with_default_style_and_id() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let card = Card {
+        id: "card",
+        title: "Title",
+    };
+    let commands = kaolin.draw::<()>(|k| {
+        k.component(&card).add(Card {
+            id: "other",
+            title: "Hi",
+        })
+    });
+    let card = commands.bounding_box("card").unwrap();
+    assert_eq!((card.width, card.height), (70.0, 40.0));
+    let other = commands.bounding_box("other").unwrap();
+    assert_eq!((other.x, other.width), (70.0, 40.0));
+    assert_eq!(commands.len(), 4);
+}
+
+/// Tests overriding the style and id of a component where it is used.
+#[test]
+fn component_style_and_id_overrides() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let card = Card {
+        id: "card",
+        title: "Title",
+    };
+    let commands = kaolin.draw::<()>(|k| {
+        k.id("first")
+            .component(&card)
+            .component_with(FlexStyle::new().sizing(sizing!(fixed!(100.0))), &card)
+    });
+    assert!(commands.bounding_box("first").is_some());
+    let card = commands.bounding_box("card").unwrap();
+    assert_eq!((card.x, card.width, card.height), (70.0, 100.0, 100.0));
+}
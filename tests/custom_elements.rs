@@ -0,0 +1,92 @@
+use kaolin::{
+    Kaolin,
+    commands::RenderCommand,
+    elements::{KaolinContainerElement, KaolinElement},
+    fixed, grow, sizing,
+    style::{FlexStyle, sizing::SizingDimensions},
+};
+
+mod common;
+use common::*;
+
+/// A bar chart owning its values, one 10px wide bar per value.
+struct Bars {
+    values: Vec<f64>,
+}
+
+impl<'frame> KaolinElement<'frame, TestColor, Vec<f64>> for Bars {
+    fn get_sizing_dimensions(&self) -> (SizingDimensions, SizingDimensions) {
+        let width = self.values.len() as f64 * 10.0;
+        (fixed!(width).into(), fixed!(50.0).into())
+    }
+
+    fn render(
+        &self,
+        offsets: (f64, f64),
+        size: (f64, f64),
+    ) -> Box<dyn Iterator<Item = RenderCommand<TestColor, Vec<f64>>> + '_> {
+        Box::new(std::iter::once(RenderCommand::Custom {
+            id: String::new(),
+            x: offsets.0,
+            y: offsets.1,
+            width: size.0,
+            height: size.1,
+            data: self.values.clone(),
+        }))
+    }
+
+    fn as_container(
+        &mut self,
+    ) -> Option<&mut dyn KaolinContainerElement<'frame, TestColor, Vec<f64>>> {
+        None
+    }
+}
+
+/// Tests adding an owned element, created within the frame.
+#[test]
+fn owned_element() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw(|k| {
+        k.with(FlexStyle::new(), |k| {
+            k.id("bars").add_element(Bars {
+                values: vec![1.0, 2.0, 3.0],
+            })
+        })
+    });
+    commands.next();
+    match commands.next() {
+        Some(RenderCommand::Custom {
+            id, width, data, ..
+        }) => {
+            assert_eq!(id, "bars");
+            assert_eq!(width, 30.0);
+            assert_eq!(data, vec![1.0, 2.0, 3.0]);
+        }
+        _ => panic!("Expected a custom command"),
+    }
+}
+
+/// Tests a closure-based element, drawn from its final size.
+#[test]
+fn closure_element() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let samples = [0.5, 0.25];
+    let commands = kaolin.draw(|k| {
+        k.with(
+            FlexStyle::new().sizing(sizing!(fixed!(200.0), fixed!(100.0))),
+            |k| {
+                k.custom(sizing!(grow!(), fixed!(20.0)), move |(width, height)| {
+                    samples
+                        .iter()
+                        .map(|sample| sample * width + height)
+                        .collect::<Vec<_>>()
+                })
+            },
+        )
+    });
+    let data = commands.into_iter().find_map(|command| match command {
+        RenderCommand::Custom { data, .. } => Some(data),
+        _ => None,
+    });
+    assert_eq!(data, Some(vec![120.0, 70.0]));
+}
@@ -0,0 +1,111 @@
+use kaolin::{
+    Kaolin,
+    commands::{BoundingBox, RenderCommand},
+    fixed, grow,
+    kaolin::id::hashed_id,
+    sizing,
+    style::{FlexStyle, TextStyle, padding::Padding},
+};
+
+mod common;
+use common::*;
+
+/// Tests that ids are carried into the render commands of their element only.
+/// The id of a container is not passed down to its children.
+#[test]
+fn ids_are_carried_into_render_commands() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let commands = kaolin.draw::<()>(|k| {
+        k.id("card").with(FlexStyle::new(), |k| {
+            k.text("untitled", TextStyle::new())
+                .id("title")
+                .text("Title", TextStyle::new())
+        })
+    });
+
+    let ids = commands
+        .map(|command| match command {
+            RenderCommand::DrawRectangle { id, .. } | RenderCommand::DrawText { id, .. } => id,
+            _ => panic!("Unexpected command"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["card", "", "title"]);
+}
+
+/// Tests querying the final bounding boxes of elements after layout.
+/// Only elements with an id are available, at any depth.
+#[test]
+fn bounding_boxes_after_layout() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let commands = kaolin.draw::<()>(|k| {
+        k.id("sidebar")
+            .with(
+                FlexStyle::new().sizing(sizing!(fixed!(200.0), grow!())),
+                |k| k,
+            )
+            .with(
+                FlexStyle::new()
+                    .sizing(sizing!(grow!()))
+                    .padding(Padding::all(10.0)),
+                |k| k.id("greeting").text("Hello", TextStyle::new()),
+            )
+    });
+
+    assert_eq!(
+        commands.bounding_box("sidebar"),
+        Some(BoundingBox {
+            x: 0.0,
+            y: 0.0,
+            width: 200.0,
+            height: 600.0,
+        })
+    );
+    assert_eq!(
+        commands.bounding_box("greeting"),
+        Some(BoundingBox {
+            x: 210.0,
+            y: 10.0,
+            width: 50.0,
+            height: 20.0,
+        })
+    );
+    assert_eq!(commands.bounding_boxes().len(), 2);
+    assert!(
+        commands
+            .bounding_box("sidebar")
+            .unwrap()
+            .contains(100.0, 300.0)
+    );
+}
+
+/// Tests ids generated from hashable keys, like the index of a list item.
+#[test]
+fn hashed_ids_for_list_items() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let commands = kaolin.draw::<()>(|k| {
+        (0..3).fold(k, |k, i| {
+            k.hashed_id(&("item", i))
+                .with(FlexStyle::new().sizing(sizing!(fixed!(30.0))), |k| k)
+        })
+    });
+
+    assert_eq!(
+        commands.bounding_box(&hashed_id(&("item", 2))).unwrap().x,
+        60.0
+    );
+    assert_eq!(commands.bounding_boxes().len(), 3);
+}
+
+/// Tests that render commands are only equal for the same element id.
+#[test]
+fn ids_are_compared() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let draw = |id: &str| {
+        kaolin
+            .draw::<()>(|k| k.id(id).text("Title", TextStyle::new()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(draw("title"), draw("title"));
+    assert_ne!(draw("title"), draw("subtitle"));
+}
@@ -0,0 +1,71 @@
+use kaolin::{
+    Kaolin, fixed, sizing,
+    style::{FlexStyle, TextStyle},
+};
+
+mod common;
+use common::*;
+
+/// Draws a toggle that flips its state when clicked, returning its label.
+fn toggle(kaolin: &Kaolin<TestColor>, drawn: bool) -> String {
+    let mut label = String::new();
+    kaolin.draw::<()>(|k| {
+        if !drawn {
+            return k;
+        }
+        let on = k.state::<bool>("toggle");
+        if k.is_clicked("toggle") {
+            on.update(|on| *on = !*on);
+        }
+        label = if on.get() { "on" } else { "off" }.to_string();
+        k.id("toggle").with(
+            FlexStyle::new().sizing(sizing!(fixed!(100.0), fixed!(50.0))),
+            |k| k.text(&label, TextStyle::new()),
+        )
+    });
+    label
+}
+
+/// Clicks at the given position, drawing one frame while pressed.
+fn click(kaolin: &mut Kaolin<TestColor>, position: (f64, f64)) {
+    kaolin.set_pointer(position, true);
+    toggle(kaolin, true);
+    kaolin.set_pointer(position, false);
+}
+
+/// Tests that the state of an element is kept between frames.
+#[test]
+fn state_is_kept_between_frames() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    assert_eq!(toggle(&kaolin, true), "off");
+    click(&mut kaolin, (10.0, 10.0));
+    assert_eq!(toggle(&kaolin, true), "on");
+    assert_eq!(toggle(&kaolin, true), "on");
+    click(&mut kaolin, (10.0, 10.0));
+    assert_eq!(toggle(&kaolin, true), "off");
+}
+
+/// Tests that the state is dropped once a frame is drawn without the element.
+#[test]
+fn state_is_evicted_when_not_drawn() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    toggle(&kaolin, true);
+    click(&mut kaolin, (10.0, 10.0));
+    assert_eq!(toggle(&kaolin, true), "on");
+    toggle(&kaolin, false);
+    assert_eq!(toggle(&kaolin, true), "off");
+}
+
+/// Tests states of different types for the same element, accessed from outside
+/// of the drawing function.
+#[test]
+fn states_by_type_from_outside() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    toggle(&kaolin, true);
+    kaolin.state::<bool>("toggle").set(true);
+    kaolin.state::<u32>("toggle").update(|count| *count += 2);
+
+    assert_eq!(toggle(&kaolin, true), "on");
+    assert_eq!(kaolin.state::<u32>("toggle").get(), 2);
+    assert_eq!(kaolin.state::<u32>("missing").id(), "missing");
+}
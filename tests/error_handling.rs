@@ -1,5 +1,5 @@
 use kaolin::{
-    Kaolin, fit, fixed, grow, sizing,
+    Kaolin, fit, fixed, grow, percent, sizing,
     style::{FlexStyle, TextStyle, layout::Layout},
 };
 
@@ -34,6 +34,20 @@ fn negative_fixed_height() {
     });
 }
 
+/// Tests error handling for percentages above 100%.
+/// Ensures that invalid fractions are rejected instead of falling back to another sizing.
+#[test]
+#[should_panic(expected = "InvalidFraction")]
+fn invalid_percent() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new().sizing(sizing!(percent!(1.5), fixed!(50.0))),
+            |k| k,
+        )
+    });
+}
+
 /// Tests error handling for negative growth factors.
 /// Verifies that negative growth factors are rejected by the system.
 #[test]
@@ -0,0 +1,302 @@
+use kaolin::{
+    Kaolin, fit, fixed, grow, percent, sizing,
+    style::{
+        FlexStyle,
+        layout::{Alignment, Direction, Layout, LineAlignment, Wrap},
+    },
+};
+
+mod common;
+use common::*;
+
+/// Tests that a wrapping row starts a new line when the next child doesn't fit.
+/// Verifies that the fit height of the container accounts for all lines and cross gaps.
+#[test]
+fn row_wrapping_fits_height_to_lines() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(100.0), fit!()))
+                .layout(Layout::new().wrap(Wrap::Wrap).gap(10.0).cross_gap(5.0)),
+            |k| {
+                (0..5).fold(k, |k, _| {
+                    k.with(
+                        FlexStyle::new().sizing(sizing!(fixed!(40.0), fixed!(20.0))),
+                        |k| k,
+                    )
+                })
+            },
+        )
+    });
+
+    // 3 lines of 20px, separated by 2 cross gaps of 5px
+    assert_size!(commands.next(), (100.0, 70.0));
+    assert_position!(commands.next(), (0.0, 0.0));
+    assert_position!(commands.next(), (50.0, 0.0));
+    assert_position!(commands.next(), (0.0, 25.0));
+    assert_position!(commands.next(), (50.0, 25.0));
+    assert_position!(commands.next(), (0.0, 50.0));
+}
+
+/// Tests children alignment within lines of different heights.
+/// Each child is centered in its own line, not in the whole container.
+#[test]
+fn row_wrapping_aligns_within_lines() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(100.0), fixed!(300.0)))
+                .layout(Layout::new().wrap(Wrap::Wrap).alignment(Alignment::Center)),
+            |k| {
+                k.with(
+                    FlexStyle::new().sizing(sizing!(fixed!(40.0), fixed!(20.0))),
+                    |k| k,
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fixed!(40.0), fixed!(40.0))),
+                    |k| k,
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fixed!(40.0), fixed!(10.0))),
+                    |k| k,
+                )
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (100.0, 300.0));
+    assert_position!(commands.next(), (0.0, 10.0)); // centered in the 40px line
+    assert_position!(commands.next(), (40.0, 0.0));
+    assert_position!(commands.next(), (0.0, 40.0)); // second line, as tall as itself
+}
+
+/// Tests center and space-between line alignments in a fixed height container.
+#[test]
+fn row_wrapping_line_alignment() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let draw = |line_alignment| {
+        kaolin.draw::<()>(move |k| {
+            k.with(
+                FlexStyle::new()
+                    .sizing(sizing!(fixed!(100.0), fixed!(100.0)))
+                    .layout(
+                        Layout::new()
+                            .wrap(Wrap::Wrap)
+                            .line_alignment(line_alignment),
+                    ),
+                |k| {
+                    (0..3).fold(k, |k, _| {
+                        k.with(
+                            FlexStyle::new().sizing(sizing!(fixed!(60.0), fixed!(20.0))),
+                            |k| k,
+                        )
+                    })
+                },
+            )
+        })
+    };
+
+    let mut commands = draw(LineAlignment::Center);
+    assert_size!(commands.next(), (100.0, 100.0));
+    assert_position!(commands.next(), (0.0, 20.0));
+    assert_position!(commands.next(), (0.0, 40.0));
+    assert_position!(commands.next(), (0.0, 60.0));
+
+    let mut commands = draw(LineAlignment::SpaceBetween);
+    assert_size!(commands.next(), (100.0, 100.0));
+    assert_position!(commands.next(), (0.0, 0.0));
+    assert_position!(commands.next(), (0.0, 40.0));
+    assert_position!(commands.next(), (0.0, 80.0));
+}
+
+/// Tests that growable children fill the remaining space of their own line.
+#[test]
+fn row_wrapping_grows_within_line() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(100.0), fit!()))
+                .layout(Layout::new().wrap(Wrap::Wrap)),
+            |k| {
+                k.with(
+                    FlexStyle::new().sizing(sizing!(fixed!(60.0), fixed!(20.0))),
+                    |k| k,
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(grow!(), fixed!(20.0))),
+                    |k| k,
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fixed!(70.0), fixed!(20.0))),
+                    |k| k,
+                )
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (100.0, 40.0));
+    assert_size!(commands.next(), (60.0, 20.0));
+    assert_multiple!(
+        commands.next(),
+        assert_size((40.0, 20.0)),
+        assert_position((60.0, 0.0))
+    );
+    assert_position!(commands.next(), (0.0, 20.0)); // didn't fit, new line
+}
+
+/// Tests wrapping in a vertical layout, where lines become columns.
+#[test]
+fn column_wrapping() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(200.0), fixed!(100.0)))
+                .layout(
+                    Layout::new()
+                        .direction(Direction::TopToBottom)
+                        .wrap(Wrap::Wrap)
+                        .cross_gap(10.0),
+                ),
+            |k| {
+                (0..3).fold(k, |k, _| {
+                    k.with(
+                        FlexStyle::new().sizing(sizing!(fixed!(30.0), fixed!(40.0))),
+                        |k| k,
+                    )
+                })
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (200.0, 100.0));
+    assert_position!(commands.next(), (0.0, 0.0));
+    assert_position!(commands.next(), (0.0, 40.0));
+    assert_position!(commands.next(), (40.0, 0.0)); // second column
+}
+
+/// Tests that the fit width of a wrapping column layout spans all of its columns.
+#[test]
+fn column_wrapping_fits_width_to_columns() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fit!(), fixed!(100.0)))
+                .layout(
+                    Layout::new()
+                        .direction(Direction::TopToBottom)
+                        .wrap(Wrap::Wrap)
+                        .cross_gap(10.0),
+                ),
+            |k| {
+                (0..5).fold(k, |k, _| {
+                    k.with(
+                        FlexStyle::new().sizing(sizing!(fixed!(30.0), fixed!(40.0))),
+                        |k| k,
+                    )
+                })
+            },
+        )
+    });
+
+    // 3 columns of 30px, separated by 2 cross gaps of 10px
+    assert_size!(commands.next(), (110.0, 100.0));
+    assert_position!(commands.next(), (0.0, 0.0));
+    assert_position!(commands.next(), (0.0, 40.0));
+    assert_position!(commands.next(), (40.0, 0.0));
+    assert_position!(commands.next(), (40.0, 40.0));
+    assert_position!(commands.next(), (80.0, 0.0));
+}
+
+/// Tests that children grow and stretch to the width of their own column.
+#[test]
+fn column_wrapping_grows_within_column() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(200.0), fixed!(100.0)))
+                .layout(
+                    Layout::new()
+                        .direction(Direction::TopToBottom)
+                        .wrap(Wrap::Wrap)
+                        .alignment(Alignment::Stretch),
+                ),
+            |k| {
+                k.with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fixed!(40.0))),
+                    |k| k,
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(grow!(), fixed!(40.0))),
+                    |k| k,
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fit!(), fixed!(40.0))),
+                    |k| {
+                        k.with(
+                            FlexStyle::new().sizing(sizing!(fixed!(20.0), fixed!(20.0))),
+                            |k| k,
+                        )
+                    },
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fixed!(30.0), fixed!(40.0))),
+                    |k| k,
+                )
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (200.0, 100.0));
+    assert_size!(commands.next(), (50.0, 40.0));
+    assert_size!(commands.next(), (50.0, 40.0)); // grown to the first column
+    // stretched to the second column, as wide as its widest child
+    assert_multiple!(
+        commands.next(),
+        assert_size((30.0, 40.0)),
+        assert_position((50.0, 0.0))
+    );
+    commands.next(); // the fixed child of the stretched container
+    assert_position!(commands.next(), (50.0, 40.0));
+}
+
+/// Tests that percentages of wrapped children account for the gaps of their own line.
+#[test]
+fn row_wrapping_percent_gaps_per_line() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(100.0), fit!()))
+                .layout(Layout::new().wrap(Wrap::Wrap).gap(10.0)),
+            |k| {
+                (0..3).fold(k, |k, _| {
+                    k.with(
+                        FlexStyle::new().sizing(sizing!(percent!(0.5), fixed!(20.0))),
+                        |k| k,
+                    )
+                })
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (100.0, 40.0));
+    // two halves of the 90px left by the gap of the first line
+    assert_multiple!(
+        commands.next(),
+        assert_size((45.0, 20.0)),
+        assert_position((0.0, 0.0))
+    );
+    assert_position!(commands.next(), (55.0, 0.0));
+    // alone on its line, without any gap
+    assert_multiple!(
+        commands.next(),
+        assert_size((50.0, 20.0)),
+        assert_position((0.0, 20.0))
+    );
+}
@@ -0,0 +1,129 @@
+use kaolin::{
+    Kaolin, fixed, grow, sizing,
+    style::{
+        FlexStyle, TextStyle,
+        floating::{AttachPoint, AttachTo, Floating},
+        padding::Padding,
+    },
+};
+
+mod common;
+use common::*;
+
+/// Tests that a floating element takes no space in the flow, and is drawn after everything else.
+#[test]
+fn floating_is_out_of_flow() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(FlexStyle::new(), |k| {
+            k.text("Hi", TextStyle::new()).with(
+                FlexStyle::new()
+                    .sizing(sizing!(fixed!(80.0), fixed!(20.0)))
+                    .floating(
+                        Floating::new()
+                            .parent_point(AttachPoint::BottomLeft)
+                            .offset((0.0, 4.0)),
+                    ),
+                |k| k,
+            )
+        })
+        .with(FlexStyle::new().sizing(sizing!(fixed!(100.0))), |k| k)
+    });
+
+    assert_size!(commands.next(), (20.0, 20.0)); // fits the text only
+    assert_position!(commands.next(), (0.0, 0.0)); // Hi
+    assert_position!(commands.next(), (20.0, 0.0)); // next sibling, right after
+    assert_multiple!(
+        commands.next(),
+        assert_size((80.0, 20.0)),
+        assert_position((0.0, 24.0)) // below its parent
+    );
+    assert!(commands.next().is_none());
+}
+
+/// Tests attaching a floating element to the root, from deep within the tree.
+#[test]
+fn floating_attached_to_root() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(100.0)))
+                .padding(Padding::all(10.0)),
+            |k| {
+                k.with(FlexStyle::new(), |k| {
+                    k.with(
+                        FlexStyle::new()
+                            .sizing(sizing!(fixed!(200.0), fixed!(100.0)))
+                            .floating(
+                                Floating::new()
+                                    .attach_to(AttachTo::Root)
+                                    .parent_point(AttachPoint::Center)
+                                    .element_point(AttachPoint::Center),
+                            ),
+                        |k| k,
+                    )
+                })
+            },
+        )
+    });
+
+    commands.next(); // outer container
+    commands.next(); // inner container
+    assert_position!(commands.next(), (300.0, 250.0)); // centered in the window
+}
+
+/// Tests that floating elements are drawn in ascending z-index order.
+#[test]
+fn floating_z_index_order() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(10.0)))
+                .floating(Floating::new().z_index(5)),
+            |k| k,
+        )
+        .with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(20.0)))
+                .floating(Floating::new().z_index(1)),
+            |k| k,
+        )
+    });
+
+    assert_size!(commands.next(), (20.0, 20.0));
+    assert_size!(commands.next(), (10.0, 10.0));
+}
+
+/// Tests that growable floating elements grow to the inner size of their parent.
+#[test]
+fn floating_grows_to_parent() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(300.0), fixed!(100.0)))
+                .padding(Padding::all(10.0)),
+            |k| {
+                k.with(
+                    FlexStyle::new()
+                        .sizing(sizing!(grow!(), fixed!(20.0)))
+                        .floating(
+                            Floating::new()
+                                .parent_point(AttachPoint::TopRight)
+                                .element_point(AttachPoint::TopRight),
+                        ),
+                    |k| k,
+                )
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (300.0, 100.0));
+    assert_multiple!(
+        commands.next(),
+        assert_size((280.0, 20.0)),
+        assert_position((20.0, 0.0))
+    );
+}
@@ -0,0 +1,158 @@
+use kaolin::{
+    Kaolin,
+    commands::RenderCommand,
+    fixed,
+    kaolin::focus::FocusDirection,
+    sizing,
+    style::{
+        FlexStyle,
+        border::Border,
+        layout::{Direction, Layout},
+    },
+};
+
+mod common;
+use common::*;
+
+/// Draws a 2x2 grid of 100x50 focusable buttons, "a" "b" on top and "c" "d" below,
+/// returning the one reported as focused by the scopes.
+fn buttons(kaolin: &Kaolin<TestColor>) -> Option<String> {
+    let mut focused = None;
+    let style = FlexStyle::new().sizing(sizing!(fixed!(100.0), fixed!(50.0)));
+    kaolin.draw::<()>(|k| {
+        focused = ["a", "b", "c", "d"]
+            .into_iter()
+            .find(|id| k.is_focused(id))
+            .map(String::from);
+        k.with(
+            FlexStyle::new().layout(Layout::new().direction(Direction::TopToBottom)),
+            |k| {
+                k.with(FlexStyle::new(), |k| {
+                    k.focusable("a")
+                        .with(style, |k| k)
+                        .focusable("b")
+                        .with(style, |k| k)
+                })
+                .with(FlexStyle::new(), |k| {
+                    k.focusable("c")
+                        .with(style, |k| k)
+                        .focusable("d")
+                        .with(style, |k| k)
+                })
+            },
+        )
+    });
+    focused
+}
+
+/// Tests moving the focus in tab order, wrapping around at both ends.
+#[test]
+fn tab_order_wraps_around() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    kaolin.focus_next(); // nothing declared yet
+    assert_eq!(buttons(&kaolin), None);
+
+    kaolin.focus_next();
+    assert_eq!(buttons(&kaolin).as_deref(), Some("a"));
+    kaolin.focus_next();
+    kaolin.focus_next();
+    kaolin.focus_next();
+    assert_eq!(buttons(&kaolin).as_deref(), Some("d"));
+    kaolin.focus_next();
+    assert_eq!(buttons(&kaolin).as_deref(), Some("a"));
+    kaolin.focus_previous();
+    assert_eq!(buttons(&kaolin).as_deref(), Some("d"));
+    assert_eq!(kaolin.focused_id().as_deref(), Some("d"));
+}
+
+/// Tests moving the focus spatially, based on the layout of the previous frame.
+#[test]
+fn directional_navigation() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    buttons(&kaolin);
+    kaolin.focus("a");
+    kaolin.focus_towards(FocusDirection::Right);
+    assert_eq!(buttons(&kaolin).as_deref(), Some("b"));
+    kaolin.focus_towards(FocusDirection::Down);
+    assert_eq!(buttons(&kaolin).as_deref(), Some("d"));
+    kaolin.focus_towards(FocusDirection::Down); // nothing below
+    assert_eq!(buttons(&kaolin).as_deref(), Some("d"));
+    kaolin.focus_towards(FocusDirection::Left);
+    kaolin.focus_towards(FocusDirection::Up);
+    assert_eq!(buttons(&kaolin).as_deref(), Some("a"));
+}
+
+/// Tests that the focus is lost when the focused element isn't drawn.
+#[test]
+fn focus_lost_when_undrawn() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    buttons(&kaolin);
+    kaolin.focus("c");
+    assert_eq!(buttons(&kaolin).as_deref(), Some("c"));
+    kaolin.draw::<()>(|k| k);
+    assert_eq!(kaolin.focused_id(), None);
+    kaolin.blur();
+    assert_eq!(buttons(&kaolin), None);
+}
+
+/// Tests that the focus outline is drawn last, around the focused element.
+#[test]
+fn focus_outline_command() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    let outline = Border::new().width(2.0).color(TestColor::Red);
+    kaolin.show_focus_outline(Some(outline));
+    buttons(&kaolin);
+    kaolin.focus("b");
+    let commands = kaolin.draw::<()>(|k| {
+        k.focusable("a")
+            .with(
+                FlexStyle::new().sizing(sizing!(fixed!(100.0), fixed!(50.0))),
+                |k| k,
+            )
+            .focusable("b")
+            .with(
+                FlexStyle::new().sizing(sizing!(fixed!(100.0), fixed!(50.0))),
+                |k| k,
+            )
+    });
+    let last = commands.into_iter().last();
+    assert_eq!(
+        last,
+        Some(RenderCommand::DrawFocusOutline {
+            id: "b".to_string(),
+            x: 100.0,
+            y: 0.0,
+            width: 100.0,
+            height: 50.0,
+            border: outline,
+        })
+    );
+}
+
+/// Tests activating the focused element, reported as a click for a single frame.
+#[test]
+fn activation_clicks_the_focused_element() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    let draw = |kaolin: &Kaolin<TestColor>| {
+        let mut clicked = Vec::new();
+        kaolin.draw::<()>(|k| {
+            clicked = ["a", "b"]
+                .into_iter()
+                .filter(|id| k.is_clicked(id))
+                .collect();
+            k.focusable("a")
+                .with(FlexStyle::new(), |k| k)
+                .focusable("b")
+                .with(FlexStyle::new(), |k| k)
+        });
+        clicked
+    };
+    draw(&kaolin);
+    kaolin.activate();
+    assert!(draw(&kaolin).is_empty()); // nothing focused
+
+    kaolin.focus("b");
+    kaolin.activate();
+    assert_eq!(draw(&kaolin), ["b"]);
+    assert!(draw(&kaolin).is_empty());
+}
@@ -0,0 +1,251 @@
+use kaolin::{
+    Kaolin,
+    commands::RenderCommand,
+    fit, fixed, grow, sizing,
+    style::{
+        FlexStyle, TextStyle,
+        grid::{GridCell, GridStyle},
+        layout::Alignment,
+    },
+};
+
+mod common;
+use common::*;
+
+/// Tests a typical form layout, with a fit column for the labels and a growing column for the inputs.
+/// Verifies that labels line up without any fixed width.
+#[test]
+fn form_with_fit_and_grow_columns() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.grid(
+            GridStyle::new()
+                .sizing(sizing!(fixed!(300.0), fit!()))
+                .columns([fit!(), grow!()])
+                .column_gap(10.0)
+                .row_gap(5.0),
+            |g| {
+                g.text("Name", TextStyle::new()) // 40px wide
+                    .with(
+                        FlexStyle::new().sizing(sizing!(grow!(), fixed!(30.0))),
+                        |k| k,
+                    )
+                    .text("Email", TextStyle::new()) // 50px wide
+                    .with(
+                        FlexStyle::new().sizing(sizing!(grow!(), fixed!(30.0))),
+                        |k| k,
+                    )
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (300.0, 65.0)); // 2 rows of 30px and a 5px gap
+    assert_position!(commands.next(), (0.0, 0.0)); // Name
+    assert_multiple!(
+        commands.next(),
+        assert_size((240.0, 30.0)),
+        assert_position((60.0, 0.0))
+    );
+    assert_position!(commands.next(), (0.0, 35.0)); // Email
+    assert_multiple!(
+        commands.next(),
+        assert_size((240.0, 30.0)),
+        assert_position((60.0, 35.0))
+    );
+}
+
+/// Tests fixed and fractional tracks, with children filling their cells.
+#[test]
+fn fixed_and_fractional_tracks() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.grid(
+            GridStyle::new()
+                .sizing(sizing!(fixed!(500.0), fixed!(200.0)))
+                .columns([fixed!(100.0), grow!(1.0), grow!(3.0)])
+                .rows([fixed!(50.0), grow!()]),
+            |g| (0..6).fold(g, |g, _| g.with(FlexStyle::new(), |k| k)),
+        )
+    });
+
+    assert_size!(commands.next(), (500.0, 200.0));
+    assert_size!(commands.next(), (100.0, 50.0));
+    assert_multiple!(
+        commands.next(),
+        assert_size((100.0, 50.0)),
+        assert_position((100.0, 0.0))
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_size((300.0, 50.0)),
+        assert_position((200.0, 0.0))
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_size((100.0, 150.0)),
+        assert_position((0.0, 50.0))
+    );
+}
+
+/// Tests explicit placement with spans, and auto placement skipping the occupied cells.
+#[test]
+fn spans_and_auto_placement() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.grid(
+            GridStyle::new()
+                .columns([fixed!(100.0), fixed!(100.0)])
+                .rows([fixed!(20.0), fixed!(20.0)])
+                .column_gap(10.0),
+            |g| {
+                g.cell(GridCell::new(0, 0).column_span(2), FlexStyle::new(), |k| k)
+                    .with(FlexStyle::new(), |k| k)
+                    .cell(GridCell::new(1, 1), FlexStyle::new(), |k| k)
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (210.0, 40.0)); // fit to the tracks
+    assert_multiple!(
+        commands.next(),
+        assert_size((210.0, 20.0)), // spans both columns and the gap
+        assert_position((0.0, 0.0))
+    );
+    assert_position!(commands.next(), (0.0, 20.0)); // first free cell
+    assert_position!(commands.next(), (110.0, 20.0));
+}
+
+/// Tests that a fit grid is sized by its content, with implicit rows.
+#[test]
+fn fit_grid_with_implicit_rows() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.grid(
+            GridStyle::new().columns([fit!(), fit!()]).column_gap(10.0),
+            |g| {
+                g.text("AB", TextStyle::new())
+                    .text("CDE", TextStyle::new())
+                    .text("F", TextStyle::new())
+            },
+        )
+    });
+
+    // columns: max(20, 10) + 10 gap + 30, rows: 2 implicit rows of 20
+    assert_size!(commands.next(), (60.0, 40.0));
+    assert_position!(commands.next(), (0.0, 0.0));
+    assert_position!(commands.next(), (30.0, 0.0));
+    assert_position!(commands.next(), (0.0, 20.0));
+}
+
+/// Tests that any element can be placed in an explicit cell: text, a nested grid
+/// and a custom element, with the next auto placed child taking the cell left free.
+#[test]
+fn any_element_in_explicit_cells() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.grid(
+            GridStyle::new()
+                .columns([fixed!(100.0), fixed!(100.0)])
+                .rows([fixed!(20.0), fixed!(20.0)]),
+            |g| {
+                g.at(GridCell::new(1, 1))
+                    .text("AB", TextStyle::new())
+                    .at(GridCell::new(0, 1))
+                    .grid(GridStyle::new().columns([fit!(), fit!()]), |g| {
+                        g.text("C", TextStyle::new()).text("D", TextStyle::new())
+                    })
+                    .at(GridCell::new(1, 0))
+                    .custom(sizing!(grow!(), fixed!(10.0)), |_| ())
+                    .text("E", TextStyle::new())
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (200.0, 40.0));
+    assert_position!(commands.next(), (100.0, 20.0)); // AB
+    assert_multiple!(
+        commands.next(),
+        assert_size((100.0, 20.0)), // the nested grid fills its cell
+        assert_position((100.0, 0.0))
+    );
+    assert_position!(commands.next(), (100.0, 0.0)); // C
+    assert_position!(commands.next(), (110.0, 0.0)); // D
+    match commands.next() {
+        Some(RenderCommand::Custom {
+            x,
+            y,
+            width,
+            height,
+            ..
+        }) => assert_eq!((x, y, width, height), (0.0, 20.0, 100.0, 10.0)),
+        _ => panic!("Expected a custom command"),
+    }
+    assert_position!(commands.next(), (0.0, 0.0)); // E, in the first free cell
+}
+
+/// Tests the alignment of children within their cells, on both axes.
+/// Children keep their own size unless they grow, and can override the grid alignment.
+#[test]
+fn alignment_within_cells() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.grid(
+            GridStyle::new()
+                .columns([fixed!(100.0), fixed!(100.0)])
+                .rows([fixed!(40.0), fixed!(40.0)])
+                .alignment(Alignment::Center),
+            |g| {
+                g.text("AB", TextStyle::new())
+                    .with(
+                        FlexStyle::new()
+                            .sizing(sizing!(fixed!(30.0), fixed!(10.0)))
+                            .align_self(Alignment::End),
+                        |k| k,
+                    )
+                    .with(
+                        FlexStyle::new().sizing(sizing!(grow!(), fixed!(10.0))),
+                        |k| k,
+                    )
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (200.0, 80.0));
+    assert_position!(commands.next(), (40.0, 10.0)); // centered 20x20 text
+    assert_multiple!(
+        commands.next(),
+        assert_size((30.0, 10.0)),
+        assert_position((170.0, 30.0)) // bottom right of its cell
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_size((100.0, 10.0)), // grows to the cell width
+        assert_position((0.0, 55.0))
+    );
+}
+
+/// Tests that children in overlapping cells are drawn on top of each other, in order.
+#[test]
+fn overlapping_cells() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.grid(
+            GridStyle::new()
+                .columns([fixed!(100.0), fixed!(100.0)])
+                .rows([fixed!(20.0)]),
+            |g| {
+                g.cell(GridCell::new(0, 0).column_span(2), FlexStyle::new(), |k| k)
+                    .at(GridCell::new(0, 1))
+                    .text("Badge", TextStyle::new())
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (200.0, 20.0));
+    assert_multiple!(
+        commands.next(),
+        assert_size((200.0, 20.0)),
+        assert_position((0.0, 0.0))
+    );
+    assert_position!(commands.next(), (100.0, 0.0)); // drawn over the spanning child
+}
@@ -0,0 +1,82 @@
+use kaolin::{
+    Kaolin, fixed, grow,
+    style::{
+        FlexStyle, TextStyle,
+        layout::{Alignment, Direction, Layout},
+        padding::Padding,
+    },
+};
+
+mod common;
+use common::*;
+
+/// Tests that the macro expands to the same commands as the builder calls.
+#[test]
+fn macro_matches_builder() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let from_macro = kaolin.draw::<()>(|k| {
+        kaolin::kaolin!(k => column(width: grow!(), height: fixed!(100.0), gap: 10.0, padding: Padding::all(5.0)) {
+            text("Title", font_size: 24.0)
+            row(id: "row", alignment: Alignment::Center, background_color: TestColor::Red) {
+                text("a")
+                text("b", style: TextStyle::new().color(TestColor::Black))
+            }
+        })
+    });
+    let from_builder = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .layout(Layout::new().direction(Direction::TopToBottom).gap(10.0))
+                .sizing(kaolin::sizing!(grow!(), fixed!(100.0)))
+                .padding(Padding::all(5.0)),
+            |k| {
+                k.text("Title", TextStyle::new().font_size(24.0))
+                    .id("row")
+                    .with(
+                        FlexStyle::new()
+                            .layout(Layout::new().alignment(Alignment::Center))
+                            .background_color(TestColor::Red),
+                        |k| {
+                            k.text("a", TextStyle::new())
+                                .text("b", TextStyle::new().color(TestColor::Black))
+                        },
+                    )
+            },
+        )
+    });
+    assert_eq!(
+        from_macro.bounding_box("row"),
+        from_builder.bounding_box("row")
+    );
+    assert_eq!(from_macro.len(), 5);
+    assert_render_commands!(from_macro, from_builder.collect());
+}
+
+/// Tests loops, conditionals and method calls within the macro.
+#[test]
+fn macro_control_flow() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let items = ["one", "two", "three"];
+    let draw = |selected: Option<usize>| {
+        kaolin
+            .draw::<()>(|k| {
+                kaolin::kaolin!(k => column {
+                    for (index, item) in items.iter().enumerate() {
+                        if selected == Some(index) {
+                            row(background_color: TestColor::Red) {
+                                text(*item)
+                            }
+                        } else {
+                            text(*item)
+                        }
+                    }
+                    if let Some(index) = selected {
+                        .text(items[index], TextStyle::new())
+                    }
+                })
+            })
+            .len()
+    };
+    assert_eq!(draw(None), 1 + 3);
+    assert_eq!(draw(Some(1)), 1 + 4 + 1);
+}
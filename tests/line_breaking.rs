@@ -0,0 +1,54 @@
+use kaolin::{
+    Kaolin,
+    style::{TextStyle, text::TextOverflow},
+};
+
+mod common;
+use common::*;
+
+/// Tests that CJK text wraps between ideographs, without starting a line with punctuation.
+#[test]
+fn cjk_text() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    // ideographs are 3 bytes wide for the test measure function
+    let commands = draw_text_in(&kaolin, 90.0, "文字列。", TextStyle::new());
+    assert_eq!(lines(commands), vec!["文字", "列。"]);
+}
+
+/// Tests breaks after hyphens, and through soft hyphens drawn as a hyphen.
+#[test]
+fn hyphens() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let commands = draw_text_in(&kaolin, 60.0, "well-known fact", TextStyle::new());
+    assert_eq!(lines(commands), vec!["well-", "known", "fact"]);
+    let commands = draw_text_in(&kaolin, 70.0, "hyphen\u{AD}ation", TextStyle::new());
+    assert_eq!(lines(commands), vec!["hyphen-", "ation"]);
+    let commands = draw_text_in(&kaolin, 200.0, "hyphen\u{AD}ation", TextStyle::new());
+    assert_eq!(lines(commands), vec!["hyphenation"]);
+}
+
+/// Tests breaks at zero width spaces, and none at non-breaking spaces.
+#[test]
+fn special_spaces() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let commands = draw_text_in(&kaolin, 40.0, "foo\u{200B}bar", TextStyle::new());
+    assert_eq!(lines(commands), vec!["foo", "bar"]);
+    let commands = draw_text_in(&kaolin, 200.0, "foo\u{200B}bar", TextStyle::new());
+    assert_eq!(lines(commands), vec!["foobar"]);
+    let commands = draw_text_in(&kaolin, 50.0, "10\u{A0}km away", TextStyle::new());
+    assert_eq!(lines(commands), vec!["10\u{A0}km", "away"]);
+}
+
+/// Tests that non-breaking spaces around a line are drawn, and kept when it's ellipsized.
+#[test]
+fn non_breaking_spaces_are_kept() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let commands = draw_text_in(&kaolin, 200.0, "\u{A0}indented ", TextStyle::new());
+    assert_eq!(lines(commands), vec!["\u{A0}indented"]);
+
+    let style = TextStyle::new()
+        .max_lines(1)
+        .overflow(TextOverflow::EndEllipsis);
+    let commands = draw_text_in(&kaolin, 100.0, "10\u{A0}km away more", style);
+    assert_eq!(lines(commands), vec!["10\u{A0}km…"]);
+}
@@ -0,0 +1,104 @@
+use kaolin::{
+    Kaolin, fit, fixed, grow, sizing,
+    style::{
+        FlexStyle,
+        layout::{Alignment, Direction, Layout},
+        padding::Padding,
+    },
+};
+
+mod common;
+use common::*;
+
+/// Tests margins between siblings in a fit container.
+/// Margins take up space in the parent, and offset the children inside of it.
+#[test]
+fn margins_offset_siblings_and_fit_parent() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(FlexStyle::new().sizing(sizing!(fit!())), |k| {
+            k.with(
+                FlexStyle::new()
+                    .sizing(sizing!(fixed!(50.0)))
+                    .margin(Padding::all(10.0)),
+                |k| k,
+            )
+            .with(
+                FlexStyle::new()
+                    .sizing(sizing!(fixed!(50.0)))
+                    .margin(Padding::left(5.0)),
+                |k| k,
+            )
+        })
+    });
+
+    assert_size!(commands.next(), (125.0, 70.0)); // 10 + 50 + 10 + 5 + 50
+    assert_multiple!(
+        commands.next(),
+        assert_size((50.0, 50.0)),
+        assert_position((10.0, 10.0))
+    );
+    assert_position!(commands.next(), (75.0, 0.0));
+}
+
+/// Tests growing and stretching children with margins.
+/// Growth fills the space left by the margins, instead of overflowing.
+#[test]
+fn margins_with_grow_and_stretch() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(400.0), fixed!(200.0)))
+                .layout(Layout::new().alignment(Alignment::Stretch)),
+            |k| {
+                k.with(
+                    FlexStyle::new()
+                        .sizing(sizing!(grow!(), fit!()))
+                        .margin(Padding::hor_ver(20.0, 10.0)),
+                    |k| k,
+                )
+                .with(FlexStyle::new().sizing(sizing!(grow!(), fit!())), |k| k)
+            },
+        )
+    });
+
+    commands.next();
+    assert_multiple!(
+        commands.next(),
+        assert_size((180.0, 180.0)),
+        assert_position((20.0, 10.0))
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_size((180.0, 200.0)),
+        assert_position((220.0, 0.0))
+    );
+}
+
+/// Tests margins in a reversed column.
+/// The margin box is mirrored, the margins stay on their own side of the element.
+#[test]
+fn margins_in_reversed_direction() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(100.0), fixed!(300.0)))
+                .layout(Layout::new().direction(Direction::BottomToTop)),
+            |k| {
+                k.with(
+                    FlexStyle::new()
+                        .sizing(sizing!(fixed!(50.0)))
+                        .margin(Padding::new(0.0, 0.0, 5.0, 20.0)),
+                    |k| k,
+                )
+                .with(FlexStyle::new().sizing(sizing!(fixed!(50.0))), |k| k)
+            },
+        )
+    });
+
+    commands.next();
+    assert_position!(commands.next(), (0.0, 230.0)); // 300 - 20 - 50
+    assert_position!(commands.next(), (0.0, 175.0)); // 230 - 5 - 50
+}
@@ -0,0 +1,178 @@
+use kaolin::{
+    Kaolin,
+    commands::{BoundingBox, RenderCommand},
+    fit, fixed, sizing,
+    style::{
+        FlexStyle,
+        layout::{Direction, Layout},
+        overflow::{BoxOverflow, Overflow},
+        padding::Padding,
+    },
+};
+
+mod common;
+use common::*;
+
+/// Tests a clipped list that doesn't fit its container.
+/// The children keep their size, and are drawn between scissor commands.
+#[test]
+fn clipped_children_keep_their_size() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(100.0)))
+                .layout(Layout::new().direction(Direction::TopToBottom))
+                .overflow(BoxOverflow::all(Overflow::Clip)),
+            |k| {
+                // without clipping, both would shrink to 50px
+                k.with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fit!())),
+                    |k| {
+                        k.with(
+                            FlexStyle::new().sizing(sizing!(fixed!(10.0), fixed!(80.0))),
+                            |k| k,
+                        )
+                    },
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fit!())),
+                    |k| k.text("hello world", Default::default()),
+                )
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (100.0, 100.0));
+    assert_eq!(
+        commands.next(),
+        Some(RenderCommand::ScissorStart {
+            id: "".to_string(),
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 100.0,
+        })
+    );
+    assert_size!(commands.next(), (50.0, 80.0));
+    assert_size!(commands.next(), (10.0, 80.0));
+    assert_multiple!(
+        commands.next(),
+        assert_size((50.0, 40.0)), // the text wraps, instead of being squashed
+        assert_position((0.0, 80.0))  // overflowing, but clipped
+    );
+    assert_text_content!(commands.next(), "hello");
+    assert_text_content!(commands.next(), "world");
+    assert_eq!(commands.next(), Some(RenderCommand::ScissorEnd));
+    assert!(commands.next().is_none());
+}
+
+/// Tests a log viewer scrolled down, clipped only vertically.
+/// The children are shifted by the scroll offset, the container stays in place.
+#[test]
+fn scroll_offset_shifts_children() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(200.0), fixed!(50.0)))
+                .padding(Padding::all(5.0))
+                .layout(Layout::new().direction(Direction::TopToBottom))
+                .overflow(BoxOverflow::new().y(Overflow::Scroll(30.0))),
+            |k| {
+                k.text("line 1", Default::default())
+                    .text("line 2", Default::default())
+                    .text("line 3", Default::default())
+            },
+        )
+    });
+
+    assert_position!(commands.next(), (0.0, 0.0));
+    assert_eq!(
+        commands.next(),
+        Some(RenderCommand::ScissorStart {
+            id: "".to_string(),
+            x: f64::NEG_INFINITY,
+            y: 0.0,
+            width: f64::INFINITY,
+            height: 50.0,
+        })
+    );
+    assert_position!(commands.next(), (5.0, -25.0)); // 5 (padding) - 30 (scroll)
+    assert_position!(commands.next(), (5.0, -5.0));
+    assert_position!(commands.next(), (5.0, 15.0));
+    assert_eq!(commands.next(), Some(RenderCommand::ScissorEnd));
+}
+
+/// Tests that the bounding boxes of scrolled children only keep their visible part,
+/// so that hidden children can't be hit by the pointer.
+#[test]
+fn bounding_boxes_are_clipped() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    let draw = |kaolin: &Kaolin<TestColor>| {
+        let mut hovered = false;
+        let commands = kaolin.draw::<()>(|k| {
+            hovered = k.is_hovered("line 1");
+            k.with(
+                FlexStyle::new()
+                    .sizing(sizing!(fixed!(200.0), fixed!(50.0)))
+                    .padding(Padding::all(5.0))
+                    .layout(Layout::new().direction(Direction::TopToBottom))
+                    .overflow(BoxOverflow::new().y(Overflow::Scroll(30.0))),
+                |k| {
+                    k.id("line 1")
+                        .text("line 1", Default::default())
+                        .id("line 2")
+                        .text("line 2", Default::default())
+                },
+            )
+        });
+        (commands, hovered)
+    };
+
+    let (commands, _) = draw(&kaolin);
+    let hidden = BoundingBox {
+        x: 5.0,
+        y: 0.0,
+        width: 60.0,
+        height: 0.0,
+    };
+    let partial = BoundingBox {
+        x: 5.0,
+        y: 0.0,
+        width: 60.0,
+        height: 15.0,
+    };
+    assert_eq!(commands.bounding_box("line 1"), Some(hidden));
+    assert_eq!(commands.bounding_box("line 2"), Some(partial));
+
+    kaolin.set_pointer((10.0, -10.0), false); // over the hidden line, outside the container
+    draw(&kaolin);
+    assert!(!draw(&kaolin).1);
+}
+
+/// Tests intersecting areas that span infinitely on an axis, like the scissor
+/// areas of containers clipping a single axis.
+#[test]
+fn intersection_with_infinite_areas() {
+    let vertical_clip = BoundingBox {
+        x: f64::NEG_INFINITY,
+        y: 10.0,
+        width: f64::INFINITY,
+        height: 50.0,
+    };
+    let screen = BoundingBox {
+        x: 0.0,
+        y: 0.0,
+        width: 800.0,
+        height: 600.0,
+    };
+    let clipped = BoundingBox {
+        x: 0.0,
+        y: 10.0,
+        width: 800.0,
+        height: 50.0,
+    };
+    assert_eq!(vertical_clip.intersection(&screen), clipped);
+    assert_eq!(screen.intersection(&vertical_clip), clipped);
+}
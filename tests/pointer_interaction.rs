@@ -0,0 +1,94 @@
+use kaolin::{
+    Kaolin, fixed, sizing,
+    style::{FlexStyle, TextStyle},
+};
+
+mod common;
+use common::*;
+
+/// Draws a 100x50 button, with a label telling its state.
+fn button(kaolin: &Kaolin<TestColor>) -> String {
+    let mut state = String::new();
+    let commands = kaolin.draw::<()>(|k| {
+        k.id("button").with(
+            FlexStyle::new().sizing(sizing!(fixed!(100.0), fixed!(50.0))),
+            |k| {
+                state = match (k.clicked(), k.pressed(), k.hovered()) {
+                    (true, _, _) => "clicked",
+                    (_, true, _) => "pressed",
+                    (_, _, true) => "hovered",
+                    _ => "idle",
+                }
+                .to_string();
+                k.text(&state, TextStyle::new())
+            },
+        )
+    });
+    assert_eq!(commands.len(), 2);
+    state
+}
+
+/// Tests the hover, press and click states of an element across frames.
+/// Elements are hit-tested against the layout of the previous frame.
+#[test]
+fn hover_press_and_click_states() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    kaolin.set_pointer((50.0, 25.0), false);
+    assert_eq!(button(&kaolin), "idle"); // no layout yet
+
+    assert_eq!(button(&kaolin), "hovered");
+    kaolin.set_pointer((50.0, 25.0), true);
+    assert_eq!(button(&kaolin), "pressed");
+    kaolin.set_pointer((60.0, 25.0), false);
+    assert_eq!(button(&kaolin), "clicked");
+    kaolin.set_pointer((60.0, 25.0), false);
+    assert_eq!(button(&kaolin), "hovered"); // clicks only last one frame
+    kaolin.set_pointer((150.0, 25.0), false);
+    assert_eq!(button(&kaolin), "idle");
+}
+
+/// Tests that releasing the pointer outside of an element doesn't click it.
+#[test]
+fn release_outside_does_not_click() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    button(&kaolin);
+    kaolin.set_pointer((50.0, 25.0), true);
+    assert_eq!(button(&kaolin), "pressed");
+    kaolin.set_pointer((500.0, 25.0), false);
+    assert_eq!(button(&kaolin), "idle");
+}
+
+/// Tests that a press started outside of an element doesn't click it when
+/// released over it.
+#[test]
+fn press_outside_does_not_click() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    button(&kaolin);
+    kaolin.set_pointer((500.0, 25.0), true);
+    assert_eq!(button(&kaolin), "idle");
+    kaolin.set_pointer((50.0, 25.0), true);
+    assert_eq!(button(&kaolin), "pressed");
+    kaolin.set_pointer((50.0, 25.0), false);
+    assert_eq!(button(&kaolin), "hovered");
+}
+
+/// Tests hit-testing other elements by id, from any scope.
+#[test]
+fn hit_testing_by_id() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    let draw = |kaolin: &Kaolin<TestColor>| {
+        let mut hovered = (false, false);
+        kaolin.draw::<()>(|k| {
+            hovered = (k.is_hovered("left"), k.is_hovered("right"));
+            k.id("left")
+                .with(FlexStyle::new().sizing(sizing!(fixed!(100.0))), |k| k)
+                .id("right")
+                .with(FlexStyle::new().sizing(sizing!(fixed!(100.0))), |k| k)
+        });
+        hovered
+    };
+    kaolin.set_pointer((150.0, 10.0), false);
+    draw(&kaolin);
+    assert_eq!(draw(&kaolin), (false, true));
+    assert!(kaolin.pointer().position == (150.0, 10.0));
+}
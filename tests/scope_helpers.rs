@@ -0,0 +1,53 @@
+use kaolin::{
+    Kaolin,
+    style::{FlexStyle, TextStyle, grid::GridStyle},
+};
+
+mod common;
+use common::*;
+
+/// A menu built from a list, with an optional footer.
+fn menu(kaolin: &Kaolin<TestColor>, items: &[&str], footer: Option<&str>) -> usize {
+    kaolin
+        .draw::<()>(|k| {
+            k.with(FlexStyle::new(), |k| {
+                k.each(items, |k, item| k.text(item, TextStyle::new()))
+                    .when(items.is_empty(), |k| k.text("Empty", TextStyle::new()))
+                    .when_some(footer, |k, footer| k.text(footer, TextStyle::new()))
+            })
+        })
+        .len()
+}
+
+/// Tests adding children from iterators, conditions and options.
+#[test]
+fn each_when_and_when_some() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    assert_eq!(menu(&kaolin, &["a", "b", "c"], None), 1 + 3);
+    assert_eq!(menu(&kaolin, &[], None), 1 + 1);
+    assert_eq!(menu(&kaolin, &["a"], Some("footer")), 1 + 2);
+}
+
+/// Tests choosing between branches, in flex containers and grids.
+#[test]
+fn when_else_and_apply() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.when_else(
+            false,
+            |k| k.text("yes", TextStyle::new()),
+            |k| k.text("no", TextStyle::new()),
+        )
+        .grid(GridStyle::new(), |g| {
+            g.apply(|g| match 2 {
+                1 => g.text("one", TextStyle::new()),
+                _ => g.each(0..2, |g, _| g.text("many", TextStyle::new())),
+            })
+        })
+    });
+    assert!(matches!(
+        commands.next(),
+        Some(kaolin::commands::RenderCommand::DrawText { text, .. }) if text == "no"
+    ));
+    assert_eq!(commands.len(), 3);
+}
@@ -1,5 +1,5 @@
 use kaolin::{
-    Kaolin, fit, fixed, grow, sizing,
+    Kaolin, fit, fixed, grow, percent, sizing,
     style::{FlexStyle, TextStyle, padding::Padding},
 };
 
@@ -207,4 +207,200 @@ fn basic_shrinking_scenario() {
     // The test validates that at least some form of constraint handling occurs
 }
 
+/// Tests height shrinking when a vertical layout overflows its container.
+/// Children shrink together, but never below their minimum height.
+#[test]
+fn vertical_shrinking_respects_min_height() {
+    let kaolin = Kaolin::new((300, 200), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(300.0), fixed!(100.0)))
+                .layout(Layout::new().direction(Direction::TopToBottom)),
+            |k| {
+                k.with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fit!(55.0, 100.0))),
+                    |k| k.with(FlexStyle::new().sizing(sizing!(fixed!(60.0))), |k| k),
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fit!(10.0, 100.0))),
+                    |k| k.with(FlexStyle::new().sizing(sizing!(fixed!(60.0))), |k| k),
+                )
+            },
+        )
+    });
+
+    // Total requested: 120px in a 100px column
+    assert_size!(commands.next(), (300.0, 100.0)); // Container
+    assert_size!(commands.next(), (50.0, 55.0)); // stopped at its min height
+    commands.next(); // fixed inner content
+    assert_multiple!(
+        commands.next(),
+        assert_size((50.0, 45.0)), // took the rest of the shrinking
+        assert_position((0.0, 55.0))
+    );
+}
+
+/// Tests that fixed heights are never shrunk, even when overflowing.
+#[test]
+fn vertical_shrinking_skips_fixed_children() {
+    let kaolin = Kaolin::new((300, 200), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(300.0), fixed!(100.0)))
+                .layout(Layout::new().direction(Direction::TopToBottom).gap(10.0)),
+            |k| {
+                k.with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fixed!(60.0))),
+                    |k| k,
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(fixed!(50.0), fit!())),
+                    |k| k.with(FlexStyle::new().sizing(sizing!(fixed!(60.0))), |k| k),
+                )
+            },
+        )
+    });
+
+    assert_size!(commands.next(), (300.0, 100.0)); // Container
+    assert_size!(commands.next(), (50.0, 60.0)); // fixed, untouched
+    assert_size!(commands.next(), (50.0, 30.0)); // 100 - 60 - 10 gap
+}
+
 use kaolin::style::layout::{Direction, Layout};
+
+/// Tests a 30/70 split of a padded panel with a gap.
+/// Percentages resolve against the inner size, and ignore the content of the children.
+#[test]
+fn percent_split_ignores_content() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(520.0), fixed!(200.0)))
+                .padding(Padding::all(10.0))
+                .layout(Layout::new().gap(100.0)),
+            |k| {
+                k.with(
+                    FlexStyle::new().sizing(sizing!(percent!(0.3), percent!(0.5))),
+                    |k| k.with(FlexStyle::new().sizing(sizing!(fixed!(300.0))), |k| k),
+                )
+                .with(
+                    FlexStyle::new().sizing(sizing!(percent!(0.7), grow!())),
+                    |k| k,
+                )
+            },
+        )
+    });
+
+    // inner width = 520 - 20 (padding) - 100 (gap) = 400
+    assert_size!(commands.next(), (520.0, 200.0));
+    assert_multiple!(
+        commands.next(),
+        assert_size((120.0, 90.0)), // height is half of 180
+        assert_position((10.0, 10.0))
+    );
+    assert_size!(commands.next(), (300.0, 300.0)); // overflows the sidebar
+    assert_multiple!(
+        commands.next(),
+        assert_size((280.0, 180.0)),
+        assert_position((230.0, 10.0))
+    );
+}
+
+/// Tests percentages next to growable siblings.
+/// Growable elements only share what the percentages leave.
+#[test]
+fn percent_with_growable_siblings() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(FlexStyle::new().sizing(sizing!(percent!(0.5))), |k| k)
+            .with(FlexStyle::new().sizing(sizing!(grow!())), |k| k)
+            .with(FlexStyle::new().sizing(sizing!(fixed!(100.0))), |k| k)
+    });
+
+    assert_size!(commands.next(), (400.0, 300.0));
+    assert_size!(commands.next(), (300.0, 600.0));
+    assert_size!(commands.next(), (100.0, 100.0));
+}
+
+/// Tests video thumbnails in a grow layout.
+/// The height follows the grown width, and the parent fits the resulting height.
+#[test]
+fn aspect_ratio_follows_grown_width() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(FlexStyle::new().sizing(sizing!(grow!(), fit!())), |k| {
+            k.with(
+                FlexStyle::new()
+                    .sizing(sizing!(grow!()))
+                    .aspect_ratio(16.0 / 9.0),
+                |k| k,
+            )
+            .with(
+                FlexStyle::new()
+                    .sizing(sizing!(grow!()))
+                    .aspect_ratio(16.0 / 9.0),
+                |k| k,
+            )
+        })
+    });
+
+    assert_size!(commands.next(), (800.0, 225.0));
+    assert_size!(commands.next(), (400.0, 225.0));
+    assert_multiple!(
+        commands.next(),
+        assert_size((400.0, 225.0)),
+        assert_position((400.0, 0.0))
+    );
+}
+
+/// Tests square avatar tiles with a maximum height.
+/// The height derived from the width is still clamped by the height's limits.
+#[test]
+fn aspect_ratio_clamped_by_height_limits() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(grow!(), fit!(100.0)))
+                .aspect_ratio(1.0),
+            |k| k,
+        )
+        .with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(50.0), grow!()))
+                .aspect_ratio(1.0),
+            |k| k,
+        )
+    });
+
+    assert_size!(commands.next(), (750.0, 100.0));
+    assert_size!(commands.next(), (50.0, 50.0)); // doesn't grow in height
+}
+
+/// Tests that invalid aspect ratios are ignored, instead of giving an infinite
+/// or negative height.
+#[test]
+fn invalid_aspect_ratio_is_ignored() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(100.0), fit!()))
+                .aspect_ratio(0.0),
+            |k| k.with(FlexStyle::new().sizing(sizing!(fixed!(20.0))), |k| k),
+        )
+        .with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(100.0), fit!()))
+                .aspect_ratio(-2.0),
+            |k| k,
+        )
+    });
+
+    assert_size!(commands.next(), (100.0, 20.0)); // fits its content
+    commands.next();
+    assert_size!(commands.next(), (100.0, 0.0));
+}
@@ -0,0 +1,92 @@
+use kaolin::{
+    Kaolin, fit, fixed, sizing,
+    style::{
+        FlexStyle, TextStyle,
+        layout::{Alignment, Direction, Layout},
+        text::TextAlignment,
+    },
+};
+
+mod common;
+use common::*;
+
+/// Tests centered and right aligned lines, offset within the final width of the element.
+#[test]
+fn center_and_right_alignment() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new().text_align(TextAlignment::Center);
+    let mut commands = draw_text_in(&kaolin, 50.0, "aaa bb", style);
+    commands.next(); // container
+    assert_multiple!(
+        commands.next(),
+        assert_text_content("aaa"),
+        assert_position((10.0, 0.0))
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_text_content("bb"),
+        assert_position((15.0, 20.0))
+    );
+
+    let style = TextStyle::new().text_align(TextAlignment::Right);
+    let mut commands = draw_text_in(&kaolin, 50.0, "aaa bb", style);
+    commands.next(); // container
+    assert_position!(commands.next(), (20.0, 0.0));
+    assert_position!(commands.next(), (30.0, 20.0));
+}
+
+/// Tests single lines stretched across a column, aligned within the stretched width.
+#[test]
+fn alignment_in_stretched_column() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(200.0), fit!()))
+                .layout(
+                    Layout::new()
+                        .direction(Direction::TopToBottom)
+                        .alignment(Alignment::Stretch),
+                ),
+            |k| {
+                k.text("aa", TextStyle::new().text_align(TextAlignment::Center))
+                    .text("aa", TextStyle::new().text_align(TextAlignment::Right))
+            },
+        )
+    });
+    assert_size!(commands.next(), (200.0, 40.0));
+    assert_position!(commands.next(), (90.0, 0.0));
+    assert_position!(commands.next(), (180.0, 20.0));
+    assert!(commands.next().is_none());
+}
+
+/// Tests justified lines, split into words spread across the width.
+/// The last line of each paragraph stays left aligned.
+#[test]
+fn justified_text() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new().text_align(TextAlignment::Justify);
+    let mut commands = draw_text_in(&kaolin, 50.0, "a bb c\nd e", style);
+    commands.next(); // container
+    assert_multiple!(
+        commands.next(),
+        assert_text_content("a"),
+        assert_position((0.0, 0.0))
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_text_content("bb"),
+        assert_position((30.0, 0.0))
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_text_content("c"),
+        assert_position((0.0, 20.0))
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_text_content("d e"),
+        assert_position((0.0, 40.0))
+    );
+    assert!(commands.next().is_none());
+}
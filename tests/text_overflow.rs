@@ -0,0 +1,76 @@
+use kaolin::{
+    Kaolin,
+    style::{TextStyle, text::TextOverflow},
+};
+
+mod common;
+use common::*;
+
+/// Tests that lines after `max_lines` are dropped, and not counted in the height.
+#[test]
+fn max_lines_clip() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new().max_lines(2);
+    let mut commands = draw_text_in(&kaolin, 30.0, "aa bb cc dd", style);
+    assert_size!(commands.next(), (30.0, 40.0));
+    assert_eq!(lines(commands), vec!["aa", "bb"]);
+}
+
+/// Tests that the last visible line ends in an ellipsis fitting the width.
+#[test]
+fn end_ellipsis() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new()
+        .max_lines(2)
+        .overflow(TextOverflow::EndEllipsis);
+    let mut commands = draw_text_in(&kaolin, 60.0, "aaaa bbbb cccc dddd", style);
+    assert_size!(commands.next(), (60.0, 40.0));
+    // the ellipsis is 3 bytes wide for the test measure function
+    assert_eq!(lines(commands), vec!["aaaa", "bbb…"]);
+}
+
+/// Tests ellipses at the start and in the middle of a single line.
+#[test]
+fn start_and_middle_ellipsis() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let path = "src/elements/text.rs";
+
+    let style = TextStyle::new()
+        .max_lines(1)
+        .overflow(TextOverflow::MiddleEllipsis);
+    let mut commands = draw_text_in(&kaolin, 100.0, path, style);
+    assert_size!(commands.next(), (100.0, 20.0));
+    assert_eq!(lines(commands), vec!["src/….rs"]);
+
+    let style = TextStyle::new()
+        .max_lines(1)
+        .overflow(TextOverflow::StartEllipsis);
+    let mut commands = draw_text_in(&kaolin, 100.0, "first line\nsecond line", style);
+    assert_size!(commands.next(), (100.0, 20.0));
+    assert_eq!(lines(commands), vec!["…st line"]);
+}
+
+/// Tests that the ellipsis stops at the first hard break, even when the rest fits.
+#[test]
+fn ellipsis_stops_at_hard_break() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new()
+        .max_lines(2)
+        .overflow(TextOverflow::EndEllipsis);
+    let mut commands = draw_text_in(&kaolin, 100.0, "aaaa bbbb cc\ndddd", style);
+    assert_size!(commands.next(), (100.0, 40.0));
+    assert_eq!(lines(commands), vec!["aaaa bbbb", "cc…"]);
+}
+
+/// Tests that ellipses never split a grapheme cluster.
+#[test]
+fn ellipsis_keeps_graphemes() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new()
+        .max_lines(1)
+        .overflow(TextOverflow::EndEllipsis);
+    // each flag is two 4 byte characters
+    let mut commands = draw_text_in(&kaolin, 150.0, "🇫🇷🇫🇷🇫🇷", style);
+    assert_size!(commands.next(), (150.0, 20.0));
+    assert_eq!(lines(commands), vec!["🇫🇷…"]);
+}
@@ -0,0 +1,84 @@
+use kaolin::{
+    Kaolin,
+    commands::RenderCommand,
+    style::{TextStyle, text::LineHeight},
+};
+
+mod common;
+use common::*;
+
+/// Tests line heights relative to the font size, with the text centered in each line.
+#[test]
+fn relative_line_height() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new()
+        .font_size(20.0)
+        .line_height(LineHeight::Relative(1.5));
+    let mut commands = draw_text_in(&kaolin, 30.0, "aa bb", style);
+    assert_size!(commands.next(), (30.0, 60.0));
+    assert_position!(commands.next(), (0.0, 5.0));
+    assert_position!(commands.next(), (0.0, 35.0));
+
+    let style = TextStyle::new().line_height(LineHeight::Absolute(24.0));
+    let mut commands = draw_text_in(&kaolin, 100.0, "aa bb", style);
+    assert_size!(commands.next(), (100.0, 24.0));
+}
+
+/// Tests extra spacing between paragraphs, but not between wrapped lines.
+#[test]
+fn paragraph_spacing() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new().paragraph_spacing(10.0);
+    let mut commands = draw_text_in(&kaolin, 30.0, "aa bb\ncc\n", style);
+    assert_size!(commands.next(), (30.0, 70.0));
+    assert_position!(commands.next(), (0.0, 0.0));
+    assert_position!(commands.next(), (0.0, 20.0));
+    assert_position!(commands.next(), (0.0, 50.0));
+}
+
+/// Tests that blank lines count toward the height of the text, with the resolved line height.
+#[test]
+fn blank_lines_take_up_height() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new().line_height(LineHeight::Absolute(24.0));
+    let mut commands = draw_text_in(&kaolin, 100.0, "aa\n\nbb", style);
+    assert_size!(commands.next(), (100.0, 72.0));
+}
+
+/// Tests that the lines after a blank line are drawn one line height further down.
+#[test]
+fn blank_lines_move_the_next_line() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = draw_text_in(&kaolin, 100.0, "a\n\nb", TextStyle::new());
+    assert_size!(commands.next(), (100.0, 60.0));
+    assert_position!(commands.next(), (0.0, 0.0));
+    assert_position!(commands.next(), (0.0, 40.0));
+
+    let style = TextStyle::new().line_height(LineHeight::Absolute(30.0));
+    let mut commands = draw_text_in(&kaolin, 100.0, "a\n\nb", style);
+    assert_size!(commands.next(), (100.0, 90.0));
+    assert_position!(commands.next(), (0.0, 5.0));
+    assert_position!(commands.next(), (0.0, 65.0));
+
+    let mut commands = draw_text_in(&kaolin, 100.0, "\na", TextStyle::new());
+    assert_size!(commands.next(), (100.0, 40.0));
+    assert_position!(commands.next(), (0.0, 20.0));
+    assert!(commands.next().is_none());
+}
+
+/// Tests that letter spacing reaches the measure function and the render commands.
+#[test]
+fn letter_spacing() {
+    let kaolin = Kaolin::new((800, 600), |text: &str, style: &TextStyle<TestColor>| {
+        let letters = text.chars().count() as f64;
+        let spacing = style.letter_spacing * (letters - 1.0).max(0.0);
+        (letters * 10.0 + spacing, 20.0)
+    });
+    let style = TextStyle::new().letter_spacing(5.0);
+    let mut commands = draw_text_in(&kaolin, 60.0, "ab cd", style);
+    assert_size!(commands.next(), (60.0, 40.0)); // "ab cd" is 70px wide
+    assert!(matches!(
+        commands.next(),
+        Some(RenderCommand::DrawText { text, letter_spacing: 5.0, .. }) if text == "ab"
+    ));
+}
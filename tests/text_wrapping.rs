@@ -193,3 +193,33 @@ fn empty_text_wrapping() {
     assert_size!(commands.next(), (100.0, 0.0)); // Container should have zero height
     // No text commands should be generated for empty text
 }
+
+/// Tests wrapping the line before a newline, and the blank line between paragraphs.
+/// The height of the container fits all the lines, the blank one included.
+#[test]
+fn wrapping_before_newlines() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let mut commands = kaolin.draw::<()>(|k| {
+        k.with(
+            FlexStyle::new().sizing(sizing!(fixed!(50.0), fit!())),
+            |k| k.text("a bb c\n\nd e", TextStyle::new()),
+        )
+    });
+
+    assert_size!(commands.next(), (50.0, 80.0));
+    assert_multiple!(
+        commands.next(),
+        assert_text_content("a bb"),
+        assert_position((0.0, 0.0))
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_text_content("c"),
+        assert_position((0.0, 20.0))
+    );
+    assert_multiple!(
+        commands.next(),
+        assert_text_content("d e"),
+        assert_position((0.0, 60.0))
+    );
+}
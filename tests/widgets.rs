@@ -0,0 +1,180 @@
+use kaolin::{
+    Kaolin, fixed,
+    kaolin::focus::FocusDirection,
+    sizing,
+    style::{
+        FlexStyle,
+        layout::{Direction, Layout},
+    },
+    widgets::WidgetStyle,
+};
+
+mod common;
+use common::*;
+
+/// Draws the given widgets in a 200px wide column.
+fn column<'frame>(
+    contents: impl FnOnce(
+        kaolin::kaolin::scope::KaolinScope<'frame, TestColor, ()>,
+    ) -> kaolin::kaolin::scope::KaolinScope<'frame, TestColor, ()>,
+) -> impl FnOnce(
+    kaolin::kaolin::scope::KaolinScope<'frame, TestColor, ()>,
+) -> kaolin::kaolin::scope::KaolinScope<'frame, TestColor, ()> {
+    |k| {
+        k.with(
+            FlexStyle::new()
+                .sizing(sizing!(fixed!(200.0), fixed!(400.0)))
+                .layout(Layout::new().direction(Direction::TopToBottom)),
+            contents,
+        )
+    }
+}
+
+/// Tests that a button reports clicks, with a hover background.
+#[test]
+fn button_reports_clicks() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    let style = WidgetStyle::new().hover_background(TestColor::Red);
+    let draw = |kaolin: &Kaolin<TestColor>| {
+        let mut clicked = false;
+        let mut commands = kaolin.draw(column(|k| {
+            let (k, ok) = k.button("ok", "OK", &style);
+            clicked = ok;
+            k
+        }));
+        commands.next(); // column
+        let hovered = matches!(
+            commands.next(),
+            Some(kaolin::commands::RenderCommand::DrawRectangle {
+                color: TestColor::Red,
+                ..
+            })
+        );
+        (clicked, hovered)
+    };
+    kaolin.set_pointer((5.0, 5.0), false);
+    assert_eq!(draw(&kaolin), (false, false)); // no layout yet
+    kaolin.set_pointer((5.0, 5.0), true);
+    assert_eq!(draw(&kaolin), (false, true));
+    kaolin.set_pointer((5.0, 5.0), false);
+    assert_eq!(draw(&kaolin), (true, true));
+    assert_eq!(draw(&kaolin), (false, true));
+}
+
+/// Tests toggling a checkbox, and selecting radio buttons.
+#[test]
+fn checkbox_and_radio_group() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    let style = WidgetStyle::new();
+    let (mut checked, mut selected) = (false, 'a');
+    let draw = |kaolin: &Kaolin<TestColor>, checked: &mut bool, selected: &mut char| {
+        let options = [('a', "A"), ('b', "B")];
+        kaolin
+            .draw(column(|k| {
+                k.checkbox("check", "Check", checked, &style)
+                    .radio_group("radio", &options, selected, &style)
+            }))
+            .len()
+    };
+    // the column, 3 rows with their indicator and label, and the fill of radio "a"
+    assert_eq!(draw(&kaolin, &mut checked, &mut selected), 1 + 9 + 1);
+
+    kaolin.set_pointer((5.0, 5.0), true);
+    draw(&kaolin, &mut checked, &mut selected);
+    kaolin.set_pointer((5.0, 5.0), false);
+    assert_eq!(draw(&kaolin, &mut checked, &mut selected), 1 + 9 + 2);
+    assert!(checked);
+
+    // each row is 28px tall, the second radio button is the third row
+    kaolin.set_pointer((5.0, 60.0), true);
+    draw(&kaolin, &mut checked, &mut selected);
+    kaolin.set_pointer((5.0, 60.0), false);
+    draw(&kaolin, &mut checked, &mut selected);
+    assert_eq!(selected, 'b');
+    assert!(checked);
+}
+
+/// Tests dragging a slider, and the fill of a progress bar.
+#[test]
+fn slider_and_progress_bar() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    let style = WidgetStyle::new();
+    let mut value = 0.0;
+    let mut draw = |kaolin: &Kaolin<TestColor>| {
+        let mut commands = kaolin.draw(column(|k| {
+            k.slider("slider", 0.0..=10.0, &mut value, &style)
+                .progress_bar(0.25, &style)
+        }));
+        commands.next(); // column
+        commands.next(); // slider track
+        commands.next(); // slider fill
+        commands.next(); // progress bar track
+        assert_size!(commands.next(), (50.0, _));
+        value
+    };
+    draw(&kaolin);
+    kaolin.set_pointer((150.0, 8.0), true);
+    assert_eq!(draw(&kaolin), 7.5);
+    kaolin.set_pointer((500.0, 8.0), true);
+    assert_eq!(draw(&kaolin), 10.0); // still dragged, past the right edge
+    kaolin.set_pointer((20.0, 8.0), true);
+    assert_eq!(draw(&kaolin), 1.0);
+    kaolin.set_pointer((20.0, 8.0), false);
+    draw(&kaolin);
+
+    // a press started elsewhere doesn't drag the slider
+    kaolin.set_pointer((500.0, 8.0), true);
+    draw(&kaolin);
+    kaolin.set_pointer((150.0, 8.0), true);
+    assert_eq!(draw(&kaolin), 1.0);
+}
+
+/// Tests stepping a focused slider with the left and right arrows, within its range.
+/// Up and down still move the focus.
+#[test]
+fn slider_keyboard_steps() {
+    let mut kaolin = Kaolin::new((800, 600), measure_text);
+    let style = WidgetStyle::new();
+    let (mut value, mut other) = (0.0, 0.0);
+    let mut draw = |kaolin: &Kaolin<TestColor>| {
+        kaolin.draw(column(|k| {
+            k.slider("slider", 0.0..=10.0, &mut value, &style).slider(
+                "other",
+                0.0..=10.0,
+                &mut other,
+                &style,
+            )
+        }));
+        (value, other)
+    };
+    kaolin.focus("slider");
+    draw(&kaolin);
+    kaolin.focus_towards(FocusDirection::Right);
+    assert_eq!(draw(&kaolin), (0.5, 0.0));
+    assert_eq!(draw(&kaolin), (0.5, 0.0)); // adjustments only last a frame
+    kaolin.focus_towards(FocusDirection::Left);
+    draw(&kaolin);
+    kaolin.focus_towards(FocusDirection::Left);
+    assert_eq!(draw(&kaolin), (0.0, 0.0)); // clamped to the start of the range
+
+    kaolin.focus_towards(FocusDirection::Down);
+    draw(&kaolin);
+    assert_eq!(kaolin.focused_id().as_deref(), Some("other"));
+}
+
+/// Tests that NaN and out of range values leave the tracks empty or full, without panicking.
+#[test]
+fn invalid_values_are_clamped() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = WidgetStyle::new();
+    let mut value = f64::NAN;
+    let mut commands = kaolin.draw(column(|k| {
+        k.slider("slider", 0.0..=10.0, &mut value, &style)
+            .progress_bar(2.0, &style)
+    }));
+    commands.next(); // column
+    commands.next(); // slider track
+    assert_size!(commands.next(), (0.0, _));
+    commands.next(); // progress bar track
+    assert_size!(commands.next(), (200.0, _));
+}
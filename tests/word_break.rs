@@ -0,0 +1,40 @@
+use kaolin::{
+    Kaolin,
+    style::{TextStyle, text::WordBreak},
+};
+
+mod common;
+use common::*;
+
+/// Tests that words wider than the element overflow it by default.
+#[test]
+fn normal_word_break() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new().word_break(WordBreak::Normal);
+    let mut commands = draw_text_in(&kaolin, 30.0, "aaaaaa bb", style);
+    assert_size!(commands.next(), (_, 40.0));
+    assert_eq!(lines(commands), vec!["aaaaaa", "bb"]);
+}
+
+/// Tests that only the words wider than the element are split.
+#[test]
+fn break_word() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new().word_break(WordBreak::BreakWord);
+    let mut commands = draw_text_in(&kaolin, 30.0, "aaaaaa bb", style);
+    assert_size!(commands.next(), (_, 60.0));
+    assert_eq!(lines(commands), vec!["aaa", "aaa", "bb"]);
+
+    let commands = draw_text_in(&kaolin, 40.0, "ab cdef", style);
+    assert_eq!(lines(commands), vec!["ab", "cdef"]);
+}
+
+/// Tests that lines are filled, breaking between any graphemes.
+#[test]
+fn break_all() {
+    let kaolin = Kaolin::new((800, 600), measure_text);
+    let style = TextStyle::new().word_break(WordBreak::BreakAll);
+    let mut commands = draw_text_in(&kaolin, 40.0, "ab cdef", style);
+    assert_size!(commands.next(), (_, 40.0));
+    assert_eq!(lines(commands), vec!["ab c", "def"]);
+}